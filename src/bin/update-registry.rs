@@ -161,6 +161,22 @@ fn load_cached_registry(path: &Path) -> HashMap<String, (String, Vec<String>)> {
         .collect()
 }
 
+/// Deprecation is curated by hand in registry.toml, so regeneration keeps it.
+fn preserve_deprecations(final_skills: &mut HashMap<String, Skill>, path: &Path) {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return;
+    };
+    let Ok(previous) = toml::from_str::<Registry>(&content) else {
+        return;
+    };
+    for (id, old) in previous.skills {
+        if let Some(skill) = final_skills.get_mut(&id) {
+            skill.deprecated = old.deprecated;
+            skill.superseded_by = old.superseded_by;
+        }
+    }
+}
+
 fn apply_classification(meta: &mut SkillMetadata, classification: &SkillClassification) {
     meta.domain = classification.domain.to_string();
     meta.tags = classification.tags.clone();
@@ -570,6 +586,7 @@ async fn main() -> Result<()> {
     }
 
     log::info!("Generated {} skills", final_skills.len());
+    preserve_deprecations(&mut final_skills, registry_path);
 
     let gen = RegistryGenerator::new(1);
     let registry = gen.generate(final_skills);
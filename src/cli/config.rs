@@ -0,0 +1,173 @@
+use crate::cli::init::{expand_tool_aliases, report_aliases, resolve_init_tools};
+use crate::cli::output::Output;
+use crate::cli::ConfigCommands;
+use crate::models::{GlobalConfig, ProjectConfig, PROJECT_CONFIG_FILE};
+use crate::utils::{Result, RulesifyError};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Keys that `config set` may change. Everything else in the project config
+/// is maintained by `skill add`/`skill remove` and is read-only here.
+pub(crate) const SETTABLE_KEYS: [&str; 1] = ["tools"];
+
+/// Returns `false` when `validate` found errors so the caller can exit non-zero.
+pub fn run(command: ConfigCommands, out: &Output) -> Result<bool> {
+    let path = Path::new(PROJECT_CONFIG_FILE);
+    match command {
+        ConfigCommands::Validate => validate(path, out),
+        ConfigCommands::Show => show(path, out).map(|_| true),
+        ConfigCommands::Get { key } => get(path, &key, out).map(|_| true),
+        ConfigCommands::Set { key, value } => set(path, &key, &value, out).map(|_| true),
+    }
+}
+
+fn load(path: &Path) -> Result<ProjectConfig> {
+    ProjectConfig::reconcile_and_load(path)?.ok_or_else(|| RulesifyError::ConfigNotFound.into())
+}
+
+fn show(path: &Path, out: &Output) -> Result<()> {
+    let config = load(path)?;
+    if out.is_json() {
+        return out.json(&config);
+    }
+    print!("{}", toml::to_string_pretty(&config)?);
+    Ok(())
+}
+
+fn get(path: &Path, key: &str, out: &Output) -> Result<()> {
+    let value = get_key(&load(path)?, key)?;
+    if out.is_json() {
+        return out.json(&value);
+    }
+    println!("{}", render_value(&value));
+    Ok(())
+}
+
+fn set(path: &Path, key: &str, value: &str, out: &Output) -> Result<()> {
+    let mut config = load(path)?;
+    let renamed = set_key(&mut config, key, value, &GlobalConfig::load()?.tool_aliases)?;
+    report_aliases(&renamed, out);
+    config.save_tools(path)?;
+
+    let updated = get_key(&config, key)?;
+    if out.is_json() {
+        return out.json(&updated);
+    }
+    println!("{} = {}", key, render_value(&updated));
+    Ok(())
+}
+
+fn validate(path: &Path, out: &Output) -> Result<bool> {
+    if !path.exists() {
+        return Err(RulesifyError::ConfigNotFound.into());
+    }
+    let issues = ProjectConfig::validate_file(path)?;
+
+    if out.is_json() {
+        out.json(&issues)?;
+        return Ok(issues.is_valid());
+    }
+
+    for warning in &issues.warnings {
+        println!("{}", out.palette.warn(&format!("! {}", warning)));
+    }
+    for error in &issues.errors {
+        println!("{}", out.palette.error(&format!("✗ {}", error)));
+    }
+    if issues.is_valid() {
+        println!(
+            "{}",
+            out.palette
+                .success(&format!("✓ {} is valid", path.display()))
+        );
+    }
+    Ok(issues.is_valid())
+}
+
+/// Looks up a dotted key such as `tools` or `installed_skills.<id>.source`.
+pub(crate) fn get_key(config: &ProjectConfig, key: &str) -> Result<toml::Value> {
+    let mut current = toml::Value::try_from(config)?;
+    let mut walked: Vec<&str> = Vec::new();
+
+    for segment in key.split('.') {
+        let table = match current {
+            toml::Value::Table(table) => table,
+            _ => return Err(unknown_key(key, &[]).into()),
+        };
+        current = match table.get(segment) {
+            Some(value) => value.clone(),
+            None => {
+                let prefix: String = walked.iter().map(|s| format!("{}.", s)).collect();
+                let mut valid: Vec<String> =
+                    table.keys().map(|k| format!("{}{}", prefix, k)).collect();
+                valid.sort();
+                return Err(unknown_key(key, &valid).into());
+            }
+        };
+        walked.push(segment);
+    }
+
+    Ok(current)
+}
+
+/// Applies `key = value`, returning any tool aliases that were expanded.
+pub(crate) fn set_key(
+    config: &mut ProjectConfig,
+    key: &str,
+    value: &str,
+    user_aliases: &BTreeMap<String, String>,
+) -> Result<Vec<(String, &'static str)>> {
+    match key {
+        "tools" => {
+            let (tools, renamed) = expand_tool_aliases(parse_list(value), user_aliases);
+            config.tools = resolve_init_tools(tools)?;
+            Ok(renamed)
+        }
+        _ => Err(RulesifyError::ConfigError(format!(
+            "'{}' cannot be set. Settable keys: {}",
+            key,
+            SETTABLE_KEYS.join(", ")
+        ))
+        .into()),
+    }
+}
+
+/// Accepts `a,b`, `a b` or a TOML array such as `["a", "b"]`.
+pub(crate) fn parse_list(value: &str) -> Vec<String> {
+    value
+        .trim()
+        .trim_matches(|c| c == '[' || c == ']')
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|s| s.trim_matches('"').trim())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn render_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Array(items) => items
+            .iter()
+            .map(render_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        toml::Value::Table(table) => toml::to_string_pretty(table)
+            .unwrap_or_default()
+            .trim_end()
+            .to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn unknown_key(key: &str, valid: &[String]) -> RulesifyError {
+    if valid.is_empty() {
+        RulesifyError::ConfigError(format!("Unknown config key '{}'", key))
+    } else {
+        RulesifyError::ConfigError(format!(
+            "Unknown config key '{}'. Valid keys: {}",
+            key,
+            valid.join(", ")
+        ))
+    }
+}
@@ -0,0 +1,105 @@
+use crate::cli::config::{get_key, parse_list, set_key};
+use crate::models::{ProjectConfig, Scope};
+use std::collections::BTreeMap;
+
+fn sample_config() -> ProjectConfig {
+    let mut config = ProjectConfig::new();
+    config.tools = vec!["cursor".to_string()];
+    config.add_skill(
+        "tdd",
+        "https://example.com/tdd",
+        "abc123",
+        Scope::Project,
+        vec![],
+    );
+    config
+}
+
+#[test]
+fn test_get_top_level_key() {
+    let value = get_key(&sample_config(), "tools").unwrap();
+    assert_eq!(value, toml::Value::Array(vec!["cursor".into()]));
+}
+
+#[test]
+fn test_get_nested_key() {
+    let value = get_key(&sample_config(), "installed_skills.tdd.commit_sha").unwrap();
+    assert_eq!(value.as_str(), Some("abc123"));
+}
+
+#[test]
+fn test_get_unknown_key_lists_valid_keys() {
+    let err = get_key(&sample_config(), "installed_skills.tdd.nope")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("installed_skills.tdd.commit_sha"));
+    assert!(err.contains("installed_skills.tdd.source"));
+}
+
+#[test]
+fn test_parse_list_forms() {
+    let expected = vec!["cursor".to_string(), "pi".to_string()];
+    assert_eq!(parse_list("cursor,pi"), expected);
+    assert_eq!(parse_list("cursor, pi"), expected);
+    assert_eq!(parse_list("cursor pi"), expected);
+    assert_eq!(parse_list(r#"["cursor", "pi"]"#), expected);
+}
+
+#[test]
+fn test_set_tools_validates_and_orders() {
+    let mut config = sample_config();
+    set_key(&mut config, "tools", "pi,claude-code", &BTreeMap::new()).unwrap();
+    assert_eq!(
+        config.tools,
+        vec!["claude-code".to_string(), "pi".to_string()]
+    );
+
+    assert!(set_key(&mut config, "tools", "vim", &BTreeMap::new()).is_err());
+    assert!(set_key(&mut config, "tools", "", &BTreeMap::new()).is_err());
+}
+
+#[test]
+fn test_set_read_only_key_errors() {
+    let mut config = sample_config();
+    let err = set_key(&mut config, "version", "2", &BTreeMap::new())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("tools"));
+}
+
+#[test]
+fn test_set_tools_expands_user_aliases() {
+    let mut config = sample_config();
+    let aliases = BTreeMap::from([("cc".to_string(), "claude-code".to_string())]);
+
+    let renamed = set_key(&mut config, "tools", "cc,claude", &aliases).unwrap();
+
+    assert_eq!(config.tools, vec!["claude-code".to_string()]);
+    assert_eq!(
+        renamed,
+        vec![
+            ("cc".to_string(), "claude-code"),
+            ("claude".to_string(), "claude-code")
+        ]
+    );
+}
+
+#[test]
+fn test_set_tools_rewrites_only_the_tools_line() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join(".rulesify.toml");
+    let original = "# team tools\ntools = [\"cursor\"]\nmirror = \"x\"\n\n\
+                    [installed_skills]\ntdd = { added = \"2024-01-01\", source = \"https://example.com/tdd\", commit_sha = \"abc\" }\n";
+    std::fs::write(&path, original).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut config = ProjectConfig::parse(&path, &content).unwrap();
+
+    set_key(&mut config, "tools", "pi,cursor", &BTreeMap::new()).unwrap();
+    config.save_tools(&path).unwrap();
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        written,
+        original.replace("tools = [\"cursor\"]", "tools = [\"cursor\", \"pi\"]")
+    );
+}
@@ -0,0 +1,382 @@
+use crate::cli::output::Output;
+use crate::installer::tool_paths::get_skills_parent_dir;
+use crate::installer::{is_supported_tool, SUPPORTED_TOOLS};
+use crate::models::{get_global_config_path, GlobalConfig, ProjectConfig, Scope};
+use crate::registry::load_builtin;
+use crate::utils::{skill_exists_on_disk, Result};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DoctorCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl DoctorCheck {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            detail: Some(detail.into()),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Warn,
+            detail: Some(detail.into()),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Fail,
+            detail: Some(detail.into()),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DoctorReport {
+    pub healthy: bool,
+    pub checks: Vec<DoctorCheck>,
+}
+
+/// Runs every health check and prints the results. Returns `false` when any
+/// check failed so the caller can exit non-zero. Checks are read-only: unlike
+/// the normal config loaders they never reconcile or rewrite config files.
+pub fn run(out: &Output) -> Result<bool> {
+    let report = diagnose(Path::new(".rulesify.toml"), &get_global_config_path());
+
+    if out.is_json() {
+        out.json(&report)?;
+    } else {
+        render_report(&report, out);
+    }
+
+    Ok(report.healthy)
+}
+
+pub(crate) fn diagnose(project_path: &Path, global_path: &Path) -> DoctorReport {
+    let mut checks = Vec::new();
+
+    checks.push(check_registry());
+
+    let (check, global) = check_global_config(global_path);
+    checks.push(check);
+    if let Some(global) = &global {
+        checks.push(check_global_skills(global));
+        if !global.groups.is_empty() {
+            checks.push(check_groups(global));
+        }
+        if !global.tool_aliases.is_empty() {
+            checks.push(check_tool_aliases(global));
+        }
+    }
+
+    let (check, project) = check_project_config(project_path);
+    checks.push(check);
+    if let Some(project) = &project {
+        checks.push(check_tools(&project.tools));
+        checks.push(check_project_skills(project));
+        checks.extend(
+            project
+                .tools
+                .iter()
+                .filter(|t| is_supported_tool(t))
+                .map(|t| check_dir_writable(t, &get_skills_parent_dir(t))),
+        );
+    }
+
+    let healthy = checks.iter().all(|c| c.status != CheckStatus::Fail);
+    DoctorReport { healthy, checks }
+}
+
+fn check_registry() -> DoctorCheck {
+    const NAME: &str = "registry";
+    match load_builtin() {
+        Ok(registry) => {
+            let dangling = registry.dangling_replacements();
+            if dangling.is_empty() {
+                DoctorCheck::pass(
+                    NAME,
+                    format!(
+                        "{} skills (updated {})",
+                        registry.skills.len(),
+                        registry.updated
+                    ),
+                )
+            } else {
+                let pairs: Vec<String> = dangling
+                    .iter()
+                    .map(|(id, replacement)| format!("{} -> {}", id, replacement))
+                    .collect();
+                DoctorCheck::warn(
+                    NAME,
+                    format!(
+                        "superseded_by points at unknown skills: {}",
+                        pairs.join(", ")
+                    ),
+                    "rulesify skill update",
+                )
+            }
+        }
+        Err(e) => DoctorCheck::fail(
+            NAME,
+            e.to_string(),
+            "reinstall rulesify; the bundled registry is corrupt",
+        ),
+    }
+}
+
+fn check_global_config(path: &Path) -> (DoctorCheck, Option<GlobalConfig>) {
+    const NAME: &str = "global config";
+    if !path.exists() {
+        return (
+            DoctorCheck::pass(NAME, format!("{} (not created yet)", path.display())),
+            None,
+        );
+    }
+    match GlobalConfig::load_from(path) {
+        Ok(config) => (
+            DoctorCheck::pass(NAME, path.display().to_string()),
+            Some(config),
+        ),
+        Err(e) => (
+            DoctorCheck::fail(
+                NAME,
+                e.to_string(),
+                format!("fix the syntax in {} or delete it", path.display()),
+            ),
+            None,
+        ),
+    }
+}
+
+fn check_project_config(path: &Path) -> (DoctorCheck, Option<ProjectConfig>) {
+    const NAME: &str = "project config";
+    if !path.exists() {
+        return (
+            DoctorCheck::warn(NAME, format!("{} not found", path.display()), "rulesify"),
+            None,
+        );
+    }
+    let parsed = std::fs::read_to_string(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|content| ProjectConfig::parse(path, &content));
+    match parsed {
+        Ok(config) => (
+            DoctorCheck::pass(NAME, path.display().to_string()),
+            Some(config),
+        ),
+        Err(e) => (
+            DoctorCheck::fail(
+                NAME,
+                e.to_string(),
+                format!(
+                    "fix the syntax in {} or delete it and re-run `rulesify`",
+                    path.display()
+                ),
+            ),
+            None,
+        ),
+    }
+}
+
+pub(crate) fn check_tools(tools: &[String]) -> DoctorCheck {
+    const NAME: &str = "configured tools";
+    let unknown: Vec<&str> = tools
+        .iter()
+        .filter(|t| !is_supported_tool(t))
+        .map(String::as_str)
+        .collect();
+
+    if unknown.is_empty() {
+        DoctorCheck::pass(NAME, tools.join(", "))
+    } else {
+        DoctorCheck::fail(
+            NAME,
+            format!("unsupported: {}", unknown.join(", ")),
+            format!(
+                "remove them from `tools` in .rulesify.toml (supported: {})",
+                SUPPORTED_TOOLS.join(", ")
+            ),
+        )
+    }
+}
+
+fn check_project_skills(config: &ProjectConfig) -> DoctorCheck {
+    const NAME: &str = "project skills";
+    let mut missing: Vec<&str> = config
+        .installed_skills
+        .keys()
+        .filter(|id| {
+            !config
+                .tools
+                .iter()
+                .any(|t| skill_exists_on_disk(t, Scope::Project, id))
+        })
+        .map(String::as_str)
+        .collect();
+    missing.sort();
+
+    if missing.is_empty() {
+        DoctorCheck::pass(NAME, format!("{} installed", config.installed_skills.len()))
+    } else {
+        DoctorCheck::fail(
+            NAME,
+            format!("missing on disk: {}", missing.join(", ")),
+            "re-add them with `rulesify skill add <id>`",
+        )
+    }
+}
+
+fn check_global_skills(config: &GlobalConfig) -> DoctorCheck {
+    const NAME: &str = "global skills";
+    let mut missing: Vec<String> = config
+        .installed_skills
+        .iter()
+        .flat_map(|(tool, skills)| {
+            skills
+                .keys()
+                .filter(|id| !skill_exists_on_disk(tool, Scope::Global, id))
+                .map(move |id| format!("{} ({})", id, tool))
+        })
+        .collect();
+    missing.sort();
+
+    if missing.is_empty() {
+        let count: usize = config.installed_skills.values().map(|s| s.len()).sum();
+        DoctorCheck::pass(NAME, format!("{} installed", count))
+    } else {
+        DoctorCheck::fail(
+            NAME,
+            format!("missing on disk: {}", missing.join(", ")),
+            "re-add them with `rulesify skill add <id> --global`",
+        )
+    }
+}
+
+fn check_groups(config: &GlobalConfig) -> DoctorCheck {
+    const NAME: &str = "groups";
+    let registry = match load_builtin() {
+        Ok(registry) => registry,
+        Err(e) => return DoctorCheck::fail(NAME, e.to_string(), "rulesify skill update"),
+    };
+    let unknown: Vec<String> = config
+        .groups
+        .iter()
+        .flat_map(|(name, ids)| {
+            ids.iter()
+                .filter(|id| registry.get_skill(id).is_none())
+                .map(move |id| format!("{} ({})", id, name))
+        })
+        .collect();
+
+    if unknown.is_empty() {
+        DoctorCheck::pass(NAME, format!("{} defined", config.groups.len()))
+    } else {
+        DoctorCheck::warn(
+            NAME,
+            format!("unknown skills: {}", unknown.join(", ")),
+            "rulesify group remove <name> <skill-id>",
+        )
+    }
+}
+
+fn check_tool_aliases(config: &GlobalConfig) -> DoctorCheck {
+    const NAME: &str = "tool aliases";
+    let problems = config.alias_problems();
+    if problems.is_empty() {
+        DoctorCheck::pass(NAME, format!("{} defined", config.tool_aliases.len()))
+    } else {
+        DoctorCheck::warn(
+            NAME,
+            problems.join("; "),
+            format!(
+                "edit [tool_aliases] in {}",
+                get_global_config_path().display()
+            ),
+        )
+    }
+}
+
+/// Checks that skills can be written under `dir`, probing the nearest
+/// existing ancestor when the directory has not been created yet.
+pub(crate) fn check_dir_writable(tool: &str, dir: &Path) -> DoctorCheck {
+    let name = format!("{} skills dir", tool);
+    let probe_dir = dir
+        .ancestors()
+        .find(|p| p.as_os_str().is_empty() || p.exists())
+        .map(|p| {
+            if p.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                p
+            }
+        })
+        .unwrap_or(Path::new("."));
+
+    let probe = probe_dir.join(format!(".rulesify-doctor-{}", std::process::id()));
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            DoctorCheck::pass(&name, dir.display().to_string())
+        }
+        Err(e) => DoctorCheck::fail(
+            &name,
+            format!("{} is not writable: {}", probe_dir.display(), e),
+            format!("check permissions on {}", probe_dir.display()),
+        ),
+    }
+}
+
+fn render_report(report: &DoctorReport, out: &Output) {
+    for check in &report.checks {
+        let line = match check.status {
+            CheckStatus::Pass => out.palette.success(&format!("✓ {}", check.name)),
+            CheckStatus::Warn => out.palette.warn(&format!("! {}", check.name)),
+            CheckStatus::Fail => out.palette.error(&format!("✗ {}", check.name)),
+        };
+        match &check.detail {
+            Some(detail) => println!("{}: {}", line, detail),
+            None => println!("{}", line),
+        }
+        if let Some(fix) = &check.fix {
+            println!("    {}", out.palette.dim(&format!("fix: {}", fix)));
+        }
+    }
+
+    let failed = report
+        .checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    println!();
+    if failed == 0 {
+        println!("{}", out.palette.success("All checks passed."));
+    } else {
+        println!(
+            "{}",
+            out.palette.error(&format!("{} check(s) failed.", failed))
+        );
+    }
+}
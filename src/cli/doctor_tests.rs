@@ -0,0 +1,58 @@
+use crate::cli::doctor::{check_dir_writable, check_tools, diagnose, CheckStatus};
+use tempfile::TempDir;
+
+#[test]
+fn test_check_tools_accepts_supported() {
+    let check = check_tools(&["cursor".to_string(), "pi".to_string()]);
+    assert_eq!(check.status, CheckStatus::Pass);
+}
+
+#[test]
+fn test_check_tools_flags_unsupported() {
+    let check = check_tools(&["cursor".to_string(), "vim".to_string()]);
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert!(check.detail.unwrap().contains("vim"));
+    assert!(check.fix.is_some());
+}
+
+#[test]
+fn test_check_dir_writable_probes_existing_ancestor() {
+    let dir = TempDir::new().unwrap();
+    let target = dir.path().join(".cursor/skills");
+
+    let check = check_dir_writable("cursor", &target);
+
+    assert_eq!(check.status, CheckStatus::Pass);
+    assert!(!target.exists(), "probe must not create the skills dir");
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_diagnose_fails_on_broken_project_config() {
+    let dir = TempDir::new().unwrap();
+    let project = dir.path().join(".rulesify.toml");
+    std::fs::write(&project, "tools = [\n").unwrap();
+
+    let report = diagnose(&project, &dir.path().join("missing.toml"));
+
+    assert!(!report.healthy);
+    let check = report
+        .checks
+        .iter()
+        .find(|c| c.name == "project config")
+        .unwrap();
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert!(project.exists());
+}
+
+#[test]
+fn test_diagnose_missing_project_config_is_only_a_warning() {
+    let dir = TempDir::new().unwrap();
+
+    let report = diagnose(
+        &dir.path().join(".rulesify.toml"),
+        &dir.path().join("missing.toml"),
+    );
+
+    assert!(report.healthy);
+}
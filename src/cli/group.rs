@@ -0,0 +1,126 @@
+use crate::cli::output::Output;
+use crate::cli::GroupCommands;
+use crate::models::{GlobalConfig, Registry};
+use crate::registry::load_builtin;
+use crate::utils::{closest_match, Result, RulesifyError};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub(crate) struct GroupEntry {
+    pub name: String,
+    pub skills: Vec<String>,
+}
+
+pub fn run(command: GroupCommands, out: &Output) -> Result<()> {
+    let mut config = GlobalConfig::load()?;
+
+    match command {
+        GroupCommands::List => {
+            let groups: Vec<GroupEntry> = config
+                .groups
+                .iter()
+                .map(|(name, skills)| GroupEntry {
+                    name: name.clone(),
+                    skills: skills.clone(),
+                })
+                .collect();
+            if out.is_json() {
+                return out.json(&groups);
+            }
+            if groups.is_empty() {
+                println!("No groups defined.");
+                println!("Create one with `rulesify group add <name> <skill-id>...`.");
+            }
+            for group in &groups {
+                println!("{} ({} skills)", group.name, group.skills.len());
+            }
+        }
+        GroupCommands::Show { name } => {
+            let skills = group_skills(&config, &name)?;
+            if out.is_json() {
+                return out.json(&GroupEntry {
+                    name,
+                    skills: skills.to_vec(),
+                });
+            }
+            println!("{}:", name);
+            for id in skills {
+                println!("  - {}", id);
+            }
+        }
+        GroupCommands::Add { name, ids } => {
+            check_skill_ids(&load_builtin()?, &ids)?;
+            add_to_group(&mut config, &name, &ids);
+            config.save()?;
+            out.status(format!(
+                "Group '{}' now has {} skills.",
+                name,
+                config.groups[&name].len()
+            ));
+        }
+        GroupCommands::Remove { name, ids } => {
+            group_skills(&config, &name)?;
+            if remove_from_group(&mut config, &name, &ids) {
+                out.status(format!("Removed group '{}'.", name));
+            } else {
+                out.status(format!(
+                    "Group '{}' now has {} skills.",
+                    name,
+                    config.groups[&name].len()
+                ));
+            }
+            config.save()?;
+        }
+    }
+    Ok(())
+}
+
+/// Looks up a group, suggesting the closest name when it does not exist.
+pub(crate) fn group_skills<'a>(config: &'a GlobalConfig, name: &str) -> Result<&'a [String]> {
+    if let Some(skills) = config.groups.get(name) {
+        return Ok(skills);
+    }
+    let hint = closest_match(name, config.groups.keys().map(String::as_str))
+        .map(|s| format!(" Did you mean '{}'?", s))
+        .unwrap_or_default();
+    Err(RulesifyError::GroupNotFound {
+        name: name.to_string(),
+        hint,
+    }
+    .into())
+}
+
+/// Errors on the first id missing from the registry, with a suggestion.
+pub(crate) fn check_skill_ids(registry: &Registry, ids: &[String]) -> Result<()> {
+    for id in ids {
+        if registry.get_skill(id).is_none() {
+            let hint = closest_match(id, registry.skills.keys().map(String::as_str))
+                .map(|s| format!(" (did you mean '{}'?)", s))
+                .unwrap_or_default();
+            return Err(RulesifyError::SkillNotFound(format!("{}{}", id, hint)).into());
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn add_to_group(config: &mut GlobalConfig, name: &str, ids: &[String]) {
+    let skills = config.groups.entry(name.to_string()).or_default();
+    for id in ids {
+        if !skills.contains(id) {
+            skills.push(id.clone());
+        }
+    }
+}
+
+/// Removes `ids` from the group, or the whole group when `ids` is empty or
+/// nothing would be left. Returns whether the group was deleted.
+pub(crate) fn remove_from_group(config: &mut GlobalConfig, name: &str, ids: &[String]) -> bool {
+    if let Some(skills) = config.groups.get_mut(name) {
+        skills.retain(|id| !ids.contains(id));
+        if ids.is_empty() || skills.is_empty() {
+            config.groups.remove(name);
+            return true;
+        }
+    }
+    false
+}
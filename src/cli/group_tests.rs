@@ -0,0 +1,71 @@
+use crate::cli::group::{add_to_group, check_skill_ids, group_skills, remove_from_group};
+use crate::models::GlobalConfig;
+use crate::registry::load_builtin;
+
+fn ids(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_add_to_group_dedups_and_keeps_order() {
+    let mut config = GlobalConfig::new();
+    add_to_group(&mut config, "backend", &ids(&["b", "a"]));
+    add_to_group(&mut config, "backend", &ids(&["a", "c"]));
+
+    assert_eq!(config.groups["backend"], ids(&["b", "a", "c"]));
+}
+
+#[test]
+fn test_remove_from_group_drops_empty_group() {
+    let mut config = GlobalConfig::new();
+    add_to_group(&mut config, "backend", &ids(&["a", "b"]));
+
+    assert!(!remove_from_group(&mut config, "backend", &ids(&["a"])));
+    assert_eq!(config.groups["backend"], ids(&["b"]));
+
+    assert!(remove_from_group(&mut config, "backend", &ids(&["b"])));
+    assert!(!config.groups.contains_key("backend"));
+}
+
+#[test]
+fn test_remove_without_ids_deletes_group() {
+    let mut config = GlobalConfig::new();
+    add_to_group(&mut config, "frontend", &ids(&["a"]));
+
+    assert!(remove_from_group(&mut config, "frontend", &[]));
+    assert!(config.groups.is_empty());
+}
+
+#[test]
+fn test_unknown_group_suggests_closest_name() {
+    let mut config = GlobalConfig::new();
+    add_to_group(&mut config, "backend", &ids(&["a"]));
+
+    let skills = group_skills(&config, "backend").unwrap();
+    assert_eq!(skills, ids(&["a"]).as_slice());
+
+    let err = group_skills(&config, "bakend").unwrap_err().to_string();
+    assert!(err.contains("Did you mean 'backend'"));
+}
+
+#[test]
+fn test_check_skill_ids_rejects_unknown_with_hint() {
+    let registry = load_builtin().unwrap();
+    let known = registry.skills.keys().next().unwrap().clone();
+
+    assert!(check_skill_ids(&registry, std::slice::from_ref(&known)).is_ok());
+
+    let typo = format!("{}x", known);
+    let err = check_skill_ids(&registry, &[typo]).unwrap_err().to_string();
+    assert!(err.contains(&format!("did you mean '{}'", known)));
+}
+
+#[test]
+fn test_groups_omitted_from_toml_when_empty() {
+    let config = GlobalConfig::new();
+    let toml = toml::to_string_pretty(&config).unwrap();
+    assert!(!toml.contains("groups"));
+
+    let parsed: GlobalConfig = toml::from_str("version = 1\n[installed_skills]\n").unwrap();
+    assert!(parsed.groups.is_empty());
+}
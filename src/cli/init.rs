@@ -1,18 +1,21 @@
+use crate::cli::output::Output;
 use crate::fetcher::ArchiveCache;
 use crate::installer::tool_paths::get_skills_parent_dir;
+use crate::installer::{canonical_tool, is_supported_tool, resolve_tool, SUPPORTED_TOOLS};
 use crate::installer::{
     execute_npx_install, install_mega_skill, install_skill, print_install_summary,
     print_uninstall_summary, resolve_pi_coverage, uninstall_skill,
 };
 use crate::models::{GlobalConfig, InstallAction, ProjectConfig, Registry, Scope};
 use crate::registry::{load_builtin, GitHubClient};
-use crate::scanner::scan_project;
+use crate::scanner::{scan_project, tool_config};
 use crate::tui::{SelectionResult, SkillSelector, ToolPicker};
-use crate::utils::{check_all_dependencies, Result};
-use std::collections::HashSet;
+use crate::utils::{check_all_dependencies, Result, RulesifyError};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
-pub async fn run(verbose: bool) -> Result<()> {
+pub async fn run(verbose: bool, out: &Output) -> Result<()> {
     let project_path = Path::new(".");
     let config_path = Path::new(".rulesify.toml");
 
@@ -27,7 +30,7 @@ pub async fn run(verbose: bool) -> Result<()> {
         println!("Existing tools: {:?}", context.existing_tools);
     }
 
-    let mut global_config = GlobalConfig::load();
+    let mut global_config = GlobalConfig::load()?;
 
     let existing_config = ProjectConfig::reconcile_and_load(config_path)?;
 
@@ -57,8 +60,19 @@ pub async fn run(verbose: bool) -> Result<()> {
             let skills_dir = get_skills_parent_dir(tool);
             if skills_dir.exists() {
                 match std::fs::remove_dir_all(&skills_dir) {
-                    Ok(_) => println!("  ✓ Removed {}/", skills_dir.display()),
-                    Err(e) => println!("  ✗ Failed to remove {}/: {}", skills_dir.display(), e),
+                    Ok(_) => println!(
+                        "  {}",
+                        out.palette
+                            .success(&format!("✓ Removed {}/", skills_dir.display()))
+                    ),
+                    Err(e) => println!(
+                        "  {}",
+                        out.palette.error(&format!(
+                            "✗ Failed to remove {}/: {}",
+                            skills_dir.display(),
+                            e
+                        ))
+                    ),
                 }
             }
         }
@@ -103,9 +117,14 @@ pub async fn run(verbose: bool) -> Result<()> {
         }
     }
 
+    // Deprecated skills stay selectable only where already installed, so
+    // they can still be removed.
     let skills_to_show: Vec<_> = registry
         .skills
         .iter()
+        .filter(|(k, v)| {
+            !v.deprecated || project_installed_ids.contains(*k) || global_installed_ids.contains(*k)
+        })
         .map(|(k, v)| (k.clone(), v.clone()))
         .collect();
 
@@ -128,7 +147,7 @@ pub async fn run(verbose: bool) -> Result<()> {
         let mut global_removed = false;
         for (id, scope) in &result.removed {
             let results = uninstall_skill(id, &tools, *scope);
-            print_uninstall_summary(&results, id);
+            print_uninstall_summary(&results, id, &out.palette);
             match scope {
                 Scope::Project => {
                     config.remove_skill(id);
@@ -246,7 +265,7 @@ pub async fn run(verbose: bool) -> Result<()> {
                     }
                 }
             };
-            print_install_summary(&results, &skill.name);
+            print_install_summary(&results, &skill.name, &out.palette);
             config.add_skill(
                 id,
                 &skill.source_url,
@@ -268,12 +287,143 @@ pub async fn run(verbose: bool) -> Result<()> {
         println!("\nNo changes to skills.");
     }
 
-    std::fs::write(config_path, toml::to_string_pretty(&config)?)?;
+    config.save(config_path)?;
     println!("\nSaved configuration to .rulesify.toml");
 
     Ok(())
 }
 
+#[derive(Debug, Serialize)]
+pub(crate) struct InitReport {
+    pub path: String,
+    pub tools: Vec<String>,
+    pub detected: bool,
+    pub overwritten: bool,
+}
+
+/// Non-interactive counterpart of `run`: writes `.rulesify.toml` with the
+/// requested tools, or the tools detected in the project when none are given.
+pub fn scaffold(requested: Vec<String>, force: bool, out: &Output) -> Result<()> {
+    let config_path = Path::new(".rulesify.toml");
+    let detected = requested.is_empty();
+    let candidates = if detected {
+        tool_config::detect(Path::new("."))?
+    } else {
+        requested
+    };
+    let (candidates, renamed) =
+        expand_tool_aliases(candidates, &GlobalConfig::load()?.tool_aliases);
+    report_aliases(&renamed, out);
+    let tools = resolve_init_tools(candidates)?;
+    let overwritten = write_initial_config(config_path, &tools, force)?;
+
+    let report = InitReport {
+        path: config_path.display().to_string(),
+        tools,
+        detected,
+        overwritten,
+    };
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+
+    let verb = if overwritten { "Updated" } else { "Created" };
+    println!(
+        "{}",
+        out.palette.success(&format!("✓ {} {}", verb, report.path))
+    );
+    println!("  tools: {}", report.tools.join(", "));
+    println!();
+    println!("Next steps:");
+    println!("  rulesify skill search <query>   find skills in the registry");
+    println!("  rulesify skill add <id>         install a skill for these tools");
+    println!("  rulesify doctor                 check the setup");
+    Ok(())
+}
+
+/// Rewrites built-in and user-defined aliases to canonical tool names and
+/// returns the `(alias, canonical)` pairs that were rewritten. Unknown names
+/// are passed through for `resolve_init_tools` to reject.
+pub(crate) fn expand_tool_aliases(
+    tools: Vec<String>,
+    user_aliases: &BTreeMap<String, String>,
+) -> (Vec<String>, Vec<(String, &'static str)>) {
+    let mut renamed = Vec::new();
+    let tools = tools
+        .into_iter()
+        .map(|tool| match resolve_tool(&tool, user_aliases) {
+            Some(name) if name != tool => {
+                renamed.push((tool, name));
+                name.to_string()
+            }
+            _ => tool,
+        })
+        .collect();
+    (tools, renamed)
+}
+
+/// Echoes alias rewrites so users see which tool they actually got.
+pub(crate) fn report_aliases(renamed: &[(String, &'static str)], out: &Output) {
+    for (alias, name) in renamed {
+        out.status(format!("Using {} (alias: {})", name, alias));
+    }
+}
+
+/// Validates and de-duplicates tool names, ordering them like the tool picker.
+/// Aliases such as `claude_code` resolve to their canonical names.
+pub(crate) fn resolve_init_tools(tools: Vec<String>) -> Result<Vec<String>> {
+    let tools: Vec<String> = tools
+        .into_iter()
+        .map(|t| canonical_tool(&t).map_or(t, str::to_string))
+        .collect();
+    if let Some(unknown) = tools.iter().find(|t| !is_supported_tool(t)) {
+        return Err(RulesifyError::ConfigError(format!(
+            "Unsupported tool '{}'. Supported tools: {}",
+            unknown,
+            SUPPORTED_TOOLS.join(", ")
+        ))
+        .into());
+    }
+    if tools.is_empty() {
+        return Err(RulesifyError::ConfigError(format!(
+            "No AI tools detected in this project. Pass them explicitly, e.g. `rulesify init --tools {}`",
+            SUPPORTED_TOOLS[0]
+        ))
+        .into());
+    }
+    Ok(SUPPORTED_TOOLS
+        .iter()
+        .filter(|t| tools.iter().any(|requested| requested == *t))
+        .map(|t| t.to_string())
+        .collect())
+}
+
+/// Writes the initial project config. An existing config is only touched with
+/// `force`, and then only its tool list is replaced so installed skills stay
+/// tracked. Returns whether an existing config was overwritten.
+pub(crate) fn write_initial_config(path: &Path, tools: &[String], force: bool) -> Result<bool> {
+    let existing = path.exists();
+    if existing && !force {
+        return Err(RulesifyError::ConfigError(format!(
+            "{} already exists. Use --force to replace its tool list, or run `rulesify` to edit it interactively",
+            path.display()
+        ))
+        .into());
+    }
+
+    let mut config = if existing {
+        let content = std::fs::read_to_string(path)?;
+        ProjectConfig::parse(path, &content)?
+    } else {
+        ProjectConfig::new()
+    };
+    config.tools = tools.to_vec();
+
+    config.save(path)?;
+    Ok(existing)
+}
+
 fn scan_disk_skills(tools: &[String], registry: &Registry) -> Vec<(String, Scope)> {
     use crate::utils::skill_exists_on_disk;
 
@@ -0,0 +1,116 @@
+use crate::cli::init::{expand_tool_aliases, resolve_init_tools, write_initial_config};
+use crate::models::{ProjectConfig, Scope};
+use std::collections::BTreeMap;
+use tempfile::TempDir;
+
+#[test]
+fn test_resolve_init_tools_orders_and_dedups() {
+    let tools = resolve_init_tools(vec![
+        "pi".to_string(),
+        "cursor".to_string(),
+        "pi".to_string(),
+    ])
+    .unwrap();
+    assert_eq!(tools, vec!["cursor".to_string(), "pi".to_string()]);
+}
+
+#[test]
+fn test_resolve_init_tools_rejects_unknown() {
+    let err = resolve_init_tools(vec!["vim".to_string()]).unwrap_err();
+    assert!(err.to_string().contains("vim"));
+}
+
+#[test]
+fn test_resolve_init_tools_accepts_aliases() {
+    let tools = resolve_init_tools(vec![
+        "cursor".to_string(),
+        "claude_code".to_string(),
+        "claude-code".to_string(),
+    ])
+    .unwrap();
+    assert_eq!(tools, vec!["claude-code".to_string(), "cursor".to_string()]);
+}
+
+#[test]
+fn test_expand_tool_aliases_prefers_builtin_names() {
+    let aliases = BTreeMap::from([
+        ("oc".to_string(), "opencode".to_string()),
+        ("cursor".to_string(), "codex".to_string()),
+    ]);
+
+    let (tools, renamed) = expand_tool_aliases(
+        vec!["oc".to_string(), "cursor".to_string(), "vim".to_string()],
+        &aliases,
+    );
+
+    assert_eq!(
+        tools,
+        vec![
+            "opencode".to_string(),
+            "cursor".to_string(),
+            "vim".to_string()
+        ]
+    );
+    assert_eq!(renamed, vec![("oc".to_string(), "opencode")]);
+}
+
+#[test]
+fn test_resolve_init_tools_requires_at_least_one() {
+    assert!(resolve_init_tools(vec![]).is_err());
+}
+
+#[test]
+fn test_write_initial_config_creates_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(".rulesify.toml");
+
+    let overwritten = write_initial_config(&path, &["cursor".to_string()], false).unwrap();
+
+    assert!(!overwritten);
+    let config: ProjectConfig = toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(config.tools, vec!["cursor".to_string()]);
+    assert!(config.installed_skills.is_empty());
+}
+
+#[test]
+fn test_write_initial_config_refuses_existing_without_force() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(".rulesify.toml");
+    std::fs::write(
+        &path,
+        "version = 1\ntools = [\"codex\"]\n[installed_skills]\n",
+    )
+    .unwrap();
+
+    assert!(write_initial_config(&path, &["cursor".to_string()], false).is_err());
+    assert!(std::fs::read_to_string(&path).unwrap().contains("codex"));
+}
+
+#[test]
+fn test_write_initial_config_force_keeps_installed_skills() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(".rulesify.toml");
+    let mut existing = ProjectConfig::new();
+    existing.tools = vec!["codex".to_string()];
+    existing.add_skill("tdd", "https://example.com", "abc", Scope::Project, vec![]);
+    std::fs::write(&path, toml::to_string_pretty(&existing).unwrap()).unwrap();
+
+    let overwritten = write_initial_config(&path, &["cursor".to_string()], true).unwrap();
+
+    assert!(overwritten);
+    let config: ProjectConfig = toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(config.tools, vec!["cursor".to_string()]);
+    assert!(config.installed_skills.contains_key("tdd"));
+}
+
+#[test]
+fn test_tools_only_config_survives_reconcile() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join(".rulesify.toml");
+    write_initial_config(&path, &["cursor".to_string()], false).unwrap();
+
+    let config = ProjectConfig::reconcile_and_load(&path).unwrap();
+
+    assert!(config.is_some());
+    assert!(path.exists());
+}
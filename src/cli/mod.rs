@@ -1,7 +1,29 @@
+pub mod config;
+pub mod doctor;
+pub mod group;
 pub mod init;
+pub mod output;
 pub mod skill;
+pub mod stats;
 
-use clap::{Parser, Subcommand};
+#[cfg(test)]
+mod config_tests;
+#[cfg(test)]
+mod doctor_tests;
+#[cfg(test)]
+mod group_tests;
+#[cfg(test)]
+mod init_tests;
+#[cfg(test)]
+mod mod_tests;
+#[cfg(test)]
+mod stats_tests;
+
+use crate::models::{ProjectConfig, PROJECT_CONFIG_FILE};
+use crate::utils::{exit_code, RulesifyError};
+use clap::{ArgAction, Parser, Subcommand};
+use output::{ColorChoice, Output, OutputFormat, PorcelainArgs};
+use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
 #[command(name = "rulesify")]
@@ -10,34 +32,141 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
 
-    #[arg(short, long)]
-    pub verbose: bool,
+    /// Increase verbosity (-v for debug logs, -vv for trace)
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Output format for command results (json prints a single document on stdout)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Human)]
+    pub output: OutputFormat,
+
+    /// Project config to use instead of searching upward for .rulesify.toml
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// When to use colored output (NO_COLOR disables `auto`)
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Create .rulesify.toml non-interactively from detected tools
+    Init {
+        /// Tools to configure (comma-separated); defaults to tools detected in the project
+        #[arg(long, value_delimiter = ',')]
+        tools: Vec<String>,
+        /// Overwrite the tool list of an existing .rulesify.toml
+        #[arg(long)]
+        force: bool,
+    },
+
     /// Manage installed skills
     Skill {
         #[command(subcommand)]
         command: SkillCommands,
     },
+
+    /// Read or change project settings in .rulesify.toml
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Manage named groups of skills
+    Group {
+        #[command(subcommand)]
+        command: GroupCommands,
+    },
+
+    /// Check configs, installed skills and skill directories for problems
+    Doctor,
+
+    /// Summarize the registry and installed skills
+    Stats,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Print the project config
+    Show,
+
+    /// Print a value by dotted key (e.g. `tools`, `installed_skills.<id>.source`)
+    Get {
+        /// Dotted config key
+        key: String,
+    },
+
+    /// Check .rulesify.toml for unsupported tools, unknown keys and version problems
+    Validate,
+
+    /// Set a value (currently `tools`, as `a,b` or `["a", "b"]`)
+    Set {
+        /// Dotted config key
+        key: String,
+        /// New value
+        value: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GroupCommands {
+    /// List defined groups
+    List,
+
+    /// Show the skills in a group
+    Show {
+        /// Group name
+        name: String,
+    },
+
+    /// Create a group or add skills to it
+    Add {
+        /// Group name
+        name: String,
+        /// Registry skill IDs to add
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
+    /// Remove skills from a group, or the whole group when no IDs are given
+    Remove {
+        /// Group name
+        name: String,
+        /// Skill IDs to remove
+        ids: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum SkillCommands {
     /// List installed skills
-    List,
+    List {
+        /// Show whether each project skill is on disk for every configured tool
+        #[arg(long)]
+        deployed: bool,
+
+        #[command(flatten)]
+        porcelain: PorcelainArgs,
+    },
 
     /// Search available skills in registry
     Search {
         /// Filter by name or description
         query: Option<String>,
+
+        #[command(flatten)]
+        porcelain: PorcelainArgs,
     },
 
     /// Add a skill from registry
     Add {
         /// Skill ID to add
-        id: String,
+        #[arg(required_unless_present = "group", conflicts_with = "group")]
+        id: Option<String>,
+        /// Add every skill in a named group (see `rulesify group`)
+        #[arg(long)]
+        group: Option<String>,
         /// Install to global skill directory instead of project
         #[arg(long)]
         global: bool,
@@ -69,10 +198,77 @@ pub enum SkillCommands {
     },
 }
 
-pub async fn run(cli: Cli) -> crate::utils::Result<()> {
-    match cli.command {
-        None => init::run(cli.verbose).await?,
-        Some(Commands::Skill { command }) => skill::run(command, cli.verbose).await?,
+impl Cli {
+    /// Default `env_logger` filter for the requested verbosity. Extra detail
+    /// is limited to rulesify's own modules so dependencies stay at `info`.
+    /// `RUST_LOG` still takes precedence when set.
+    pub fn log_filter(&self) -> &'static str {
+        match self.verbose {
+            0 => "info",
+            1 => "info,rulesify=debug",
+            _ => "info,rulesify=trace",
+        }
+    }
+}
+
+/// Project-scoped skill paths are relative, so commands run from the project
+/// root: the directory of `--config` if given, otherwise the nearest
+/// directory at or above the current one that holds `.rulesify.toml`.
+fn enter_project_root(explicit: Option<&Path>) -> crate::utils::Result<()> {
+    let config_path = match explicit {
+        Some(path) => Some(explicit_config_path(path)?),
+        None => ProjectConfig::discover(&std::env::current_dir()?),
+    };
+    if let Some(root) = config_path.as_deref().and_then(Path::parent) {
+        if !root.as_os_str().is_empty() {
+            log::debug!("Project root: {}", root.display());
+            std::env::set_current_dir(root)?;
+        }
     }
     Ok(())
 }
+
+fn explicit_config_path(path: &Path) -> crate::utils::Result<PathBuf> {
+    let path = if path.is_dir() {
+        path.join(PROJECT_CONFIG_FILE)
+    } else {
+        path.to_path_buf()
+    };
+    if path.file_name().and_then(|n| n.to_str()) != Some(PROJECT_CONFIG_FILE) {
+        return Err(RulesifyError::ConfigError(format!(
+            "--config must point to a {} file or its directory",
+            PROJECT_CONFIG_FILE
+        ))
+        .into());
+    }
+    Ok(path)
+}
+
+/// Runs the parsed command and returns the process exit code for commands
+/// that complete but report failed checks (see [`exit_code`]).
+pub async fn run(cli: Cli) -> crate::utils::Result<i32> {
+    let out = Output::new(cli.output, cli.color);
+    match (&cli.command, &cli.config) {
+        (Some(Commands::Init { .. }), None) => {}
+        (_, config) => enter_project_root(config.as_deref())?,
+    }
+    let verbose = cli.verbose > 0;
+    match cli.command {
+        None => init::run(verbose, &out).await?,
+        Some(Commands::Init { tools, force }) => init::scaffold(tools, force, &out)?,
+        Some(Commands::Skill { command }) => skill::run(command, verbose, &out).await?,
+        Some(Commands::Config { command }) => {
+            if !config::run(command, &out)? {
+                return Ok(exit_code::CHECK_FAILED);
+            }
+        }
+        Some(Commands::Group { command }) => group::run(command, &out)?,
+        Some(Commands::Doctor) => {
+            if !doctor::run(&out)? {
+                return Ok(exit_code::CHECK_FAILED);
+            }
+        }
+        Some(Commands::Stats) => stats::run(&out)?,
+    }
+    Ok(exit_code::SUCCESS)
+}
@@ -0,0 +1,147 @@
+use crate::cli::output::{write_json, write_porcelain};
+use crate::cli::{run, Cli, Commands, SkillCommands};
+use crate::utils::exit_code;
+use clap::Parser;
+use serial_test::serial;
+
+#[test]
+fn test_default_verbosity_logs_info() {
+    let cli = Cli::try_parse_from(["rulesify", "skill", "list"]).unwrap();
+    assert_eq!(cli.verbose, 0);
+    assert_eq!(cli.log_filter(), "info");
+}
+
+#[test]
+fn test_verbose_flag_enables_debug() {
+    let cli = Cli::try_parse_from(["rulesify", "--verbose", "skill", "list"]).unwrap();
+    assert_eq!(cli.verbose, 1);
+    assert_eq!(cli.log_filter(), "info,rulesify=debug");
+}
+
+#[test]
+fn test_repeated_verbose_enables_trace() {
+    let cli = Cli::try_parse_from(["rulesify", "-vv", "skill", "list"]).unwrap();
+    assert_eq!(cli.verbose, 2);
+    assert_eq!(cli.log_filter(), "info,rulesify=trace");
+}
+
+#[test]
+fn test_verbose_accepted_after_subcommand() {
+    let cli = Cli::try_parse_from(["rulesify", "skill", "add", "foo", "-v"]).unwrap();
+    assert_eq!(cli.verbose, 1);
+}
+
+#[test]
+fn test_explicit_config_accepts_directory() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = super::explicit_config_path(dir.path()).unwrap();
+    assert_eq!(path, dir.path().join(".rulesify.toml"));
+}
+
+#[test]
+fn test_explicit_config_rejects_other_file_names() {
+    assert!(super::explicit_config_path(std::path::Path::new("rules.toml")).is_err());
+    assert!(super::explicit_config_path(std::path::Path::new("proj/.rulesify.toml")).is_ok());
+}
+
+#[test]
+fn test_porcelain_flags_select_terminator() {
+    let terminator = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+        Some(Commands::Skill {
+            command: SkillCommands::Search { porcelain, .. },
+        }) => porcelain.terminator(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(terminator(&["rulesify", "skill", "search"]), None);
+    assert_eq!(
+        terminator(&["rulesify", "skill", "search", "--porcelain"]),
+        Some('\n')
+    );
+    assert_eq!(
+        terminator(&["rulesify", "skill", "search", "-z"]),
+        Some('\0')
+    );
+}
+
+#[test]
+fn test_write_porcelain_terminates_every_entry() {
+    let mut buf = Vec::new();
+    write_porcelain(&mut buf, ["tdd", "lint"], '\0').unwrap();
+    assert_eq!(buf, b"tdd\0lint\0");
+}
+
+struct ClosedPipe;
+
+impl std::io::Write for ClosedPipe {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Err(std::io::ErrorKind::BrokenPipe.into())
+    }
+}
+
+#[test]
+fn test_write_json_treats_broken_pipe_as_success() {
+    write_json(&mut ClosedPipe, &serde_json::json!({ "error": "x" })).unwrap();
+    write_porcelain(&mut ClosedPipe, ["tdd"], '\n').unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_failing_config_validate_exits_with_check_failed() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join(".rulesify.toml"),
+        "version = 1\ntools = [\"vim\"]\n",
+    )
+    .unwrap();
+    let cli = Cli::try_parse_from([
+        "rulesify",
+        "--config",
+        dir.path().to_str().unwrap(),
+        "config",
+        "validate",
+    ])
+    .unwrap();
+
+    let original_dir = std::env::current_dir().unwrap();
+    let code = run(cli).await;
+    std::env::set_current_dir(&original_dir).unwrap();
+
+    assert_eq!(code.unwrap(), exit_code::CHECK_FAILED);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_config_set_keeps_comments_and_unknown_keys() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join(".rulesify.toml");
+    std::fs::write(
+        &path,
+        "# keep me\nversion = 1\ntools = [\"cursor\"]\nfoo = \"bar\"\n",
+    )
+    .unwrap();
+    let cli = Cli::try_parse_from([
+        "rulesify",
+        "--config",
+        dir.path().to_str().unwrap(),
+        "config",
+        "set",
+        "tools",
+        "claude-code,cursor",
+    ])
+    .unwrap();
+
+    let original_dir = std::env::current_dir().unwrap();
+    let code = run(cli).await;
+    std::env::set_current_dir(&original_dir).unwrap();
+
+    assert_eq!(code.unwrap(), exit_code::SUCCESS);
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert!(written.starts_with("# keep me\n"));
+    assert!(written.contains("foo = \"bar\""));
+    assert!(written.contains("\"claude-code\""));
+}
@@ -0,0 +1,127 @@
+use crate::utils::style::auto_color_enabled;
+use crate::utils::{Palette, Result};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::{ErrorKind, IsTerminal, Write};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => auto_color_enabled(
+                std::env::var_os("NO_COLOR").as_deref(),
+                std::io::stdout().is_terminal(),
+            ),
+        }
+    }
+}
+
+/// Script-friendly listing flags shared by `skill list` and `skill search`.
+/// Either flag prints bare ids and takes precedence over `--output`.
+#[derive(Debug, Clone, Copy, Default, Args)]
+pub struct PorcelainArgs {
+    /// Print one skill id per line with no headers or decoration
+    #[arg(long)]
+    pub porcelain: bool,
+
+    /// Like --porcelain, but separate ids with NUL bytes (for `xargs -0`)
+    #[arg(short = 'z', long)]
+    pub null: bool,
+}
+
+impl PorcelainArgs {
+    /// The entry terminator to use, or `None` for the normal rendering.
+    pub fn terminator(self) -> Option<char> {
+        if self.null {
+            Some('\0')
+        } else if self.porcelain {
+            Some('\n')
+        } else {
+            None
+        }
+    }
+}
+
+/// Writes each id followed by `terminator`. A closed pipe (e.g. `| head`)
+/// ends the listing quietly instead of failing.
+pub fn write_porcelain<I, S>(w: &mut impl Write, ids: I, terminator: char) -> Result<()>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let result = ids
+        .into_iter()
+        .try_for_each(|id| write!(w, "{}{}", id.as_ref(), terminator))
+        .and_then(|_| w.flush());
+    match result {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        other => Ok(other?),
+    }
+}
+
+/// Writes `value` as pretty JSON followed by a newline. Like
+/// [`write_porcelain`], a closed pipe is not an error.
+pub fn write_json<T: Serialize>(w: &mut impl Write, value: &T) -> Result<()> {
+    let text = serde_json::to_string_pretty(value)?;
+    match writeln!(w, "{}", text).and_then(|_| w.flush()) {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        other => Ok(other?),
+    }
+}
+
+/// Routes command output. In human mode everything goes to stdout as before;
+/// in JSON mode stdout carries a single JSON document and progress messages
+/// move to stderr so the result can be piped into other tools.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Output {
+    pub format: OutputFormat,
+    pub palette: Palette,
+}
+
+impl Output {
+    pub fn new(format: OutputFormat, color: ColorChoice) -> Self {
+        let palette = if format == OutputFormat::Json {
+            Palette::plain()
+        } else {
+            Palette::new(color.enabled())
+        };
+        Self { format, palette }
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+
+    pub fn status(&self, message: impl Display) {
+        if self.is_json() {
+            eprintln!("{}", message);
+        } else {
+            println!("{}", message);
+        }
+    }
+
+    pub fn warn(&self, message: impl Display) {
+        self.status(self.palette.warn(&message.to_string()));
+    }
+
+    pub fn json<T: Serialize>(&self, value: &T) -> Result<()> {
+        write_json(&mut std::io::stdout().lock(), value)
+    }
+}
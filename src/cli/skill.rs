@@ -1,37 +1,269 @@
+use crate::cli::output::{write_porcelain, Output, PorcelainArgs};
 use crate::cli::SkillCommands;
 use crate::fetcher::ArchiveCache;
 use crate::installer::{
     execute_npx_install, generate_install_instructions, generate_uninstall_instructions,
-    install_mega_skill, install_skill, print_install_summary, print_uninstall_summary,
-    resolve_pi_coverage, uninstall_skill,
+    get_skill_folder, install_mega_skill, install_skill, print_install_summary,
+    print_uninstall_summary, resolve_pi_coverage, uninstall_skill, InstallResult, UninstallResult,
 };
+use crate::models::skill::deprecation_notice;
 use crate::models::{
-    get_global_config_path, GlobalConfig, InstallAction, ProjectConfig, Registry, Scope,
+    get_global_config_path, GlobalConfig, InstallAction, InstalledSkill, ProjectConfig, Registry,
+    Scope, Skill,
 };
 use crate::registry::{fetch_registry, load_builtin, GitHubClient, RegistryCache};
-use crate::utils::{check_all_dependencies, Result, RulesifyError};
+use crate::utils::fs::atomic_write;
+use crate::utils::{
+    check_all_dependencies, parse_toml_file, skill_exists_on_disk, truncate_chars, Result,
+    RulesifyError,
+};
+use serde::Serialize;
+use std::collections::BTreeSet;
 use std::path::Path;
 
-pub async fn run(command: SkillCommands, verbose: bool) -> Result<()> {
+pub async fn run(command: SkillCommands, verbose: bool, out: &Output) -> Result<()> {
     match command {
-        SkillCommands::List => list_skills(verbose),
-        SkillCommands::Search { query } => search_skills(query, verbose),
+        SkillCommands::List {
+            deployed,
+            porcelain,
+        } => list_skills(verbose, deployed, porcelain, out),
+        SkillCommands::Search { query, porcelain } => search_skills(query, verbose, porcelain, out),
         SkillCommands::Add {
-            id,
+            id: Some(id),
             global,
             agent_mode,
-        } => add_skill(id, global, agent_mode, verbose).await,
+            ..
+        } => add_skill(id, global, agent_mode, verbose, out).await,
+        SkillCommands::Add {
+            group: Some(group),
+            global,
+            agent_mode,
+            ..
+        } => add_group(&group, global, agent_mode, out).await,
+        SkillCommands::Add { .. } => unreachable!("clap requires an id or --group"),
         SkillCommands::Remove {
             id,
             global,
             agent_mode,
-        } => remove_skill(id, global, agent_mode, verbose),
+        } => remove_skill(id, global, agent_mode, verbose, out),
         SkillCommands::Update { agent_mode, force } => {
-            update_directory_registry(agent_mode, force, verbose).await
+            update_directory_registry(agent_mode, force, verbose, out).await
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ListedSkill {
+    pub(crate) id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tool: Option<String>,
+    pub(crate) scope: Scope,
+    pub(crate) added: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) updated: Option<String>,
+    pub(crate) source: String,
+    pub(crate) commit_sha: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) covered_tools: Vec<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) deprecated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) superseded_by: Option<String>,
+    /// Per-tool presence on disk, filled in by `skill list --deployed`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) deployment: Vec<ToolDeployment>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct ToolDeployment {
+    pub(crate) tool: String,
+    pub(crate) status: DeploymentStatus,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DeploymentStatus {
+    Current,
+    Missing,
+    /// On disk, but installed from a different commit than the registry's.
+    Stale,
+}
+
+impl DeploymentStatus {
+    fn marker(self) -> &'static str {
+        match self {
+            Self::Current => "✓",
+            Self::Missing => "✗",
+            Self::Stale => "~",
+        }
+    }
+}
+
+impl ListedSkill {
+    fn new(id: String, tool: Option<String>, info: InstalledSkill) -> Self {
+        Self {
+            id,
+            tool,
+            scope: info.scope,
+            added: info.added,
+            updated: info.updated,
+            source: info.source,
+            commit_sha: info.commit_sha,
+            covered_tools: info.covered_tools,
+            deprecated: false,
+            superseded_by: None,
+            deployment: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct SkillListReport {
+    pub(crate) global: Vec<ListedSkill>,
+    pub(crate) project: Vec<ListedSkill>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SearchHit {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) domain: String,
+    pub(crate) stars: u32,
+    pub(crate) score: Option<f32>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) is_mega_skill: bool,
+    pub(crate) source_url: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) deprecated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) superseded_by: Option<String>,
+}
+
+impl SearchHit {
+    fn new(id: &str, skill: &Skill) -> Self {
+        Self {
+            id: id.to_string(),
+            name: skill.name.clone(),
+            description: skill.description.clone(),
+            domain: skill.domain.clone(),
+            stars: skill.stars,
+            score: skill.score,
+            tags: skill.tags.clone(),
+            is_mega_skill: skill.is_mega_skill,
+            source_url: skill.source_url.clone(),
+            deprecated: skill.deprecated,
+            superseded_by: skill.superseded_by.clone(),
         }
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ActionStatus {
+    Installed,
+    Removed,
+    AlreadyGlobal,
+    AlreadyInstalled,
+    NotInstalled,
+    CustomCommand,
+    Instructions,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ToolOutcome {
+    pub(crate) tool: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) path: Option<String>,
+    pub(crate) success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) files_created: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) warning: Option<String>,
+}
+
+impl ToolOutcome {
+    fn from_install(result: &InstallResult, path: Option<String>) -> Self {
+        Self {
+            tool: result.tool.clone(),
+            path,
+            success: result.success,
+            files_created: Some(result.files_created),
+            error: result.error.clone(),
+            warning: result.warning.clone(),
+        }
+    }
+
+    fn from_uninstall(result: &UninstallResult, path: Option<String>) -> Self {
+        Self {
+            tool: result.tool.clone(),
+            path,
+            success: result.folder_deleted,
+            files_created: None,
+            error: result.error.clone(),
+            warning: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SkillActionReport {
+    pub(crate) id: String,
+    pub(crate) scope: Scope,
+    pub(crate) status: ActionStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) tools: Vec<ToolOutcome>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) covered_tools: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) instructions: Option<String>,
+}
+
+impl SkillActionReport {
+    fn new(id: &str, scope: Scope, status: ActionStatus) -> Self {
+        Self {
+            id: id.to_string(),
+            scope,
+            status,
+            tools: Vec::new(),
+            covered_tools: Vec::new(),
+            instructions: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct UpdatedSkill {
+    pub(crate) id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tool: Option<String>,
+    pub(crate) scope: Scope,
+    pub(crate) old_sha: String,
+    pub(crate) new_sha: String,
+    pub(crate) tools: Vec<ToolOutcome>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct UpdateReport {
+    pub(crate) registry_updated: bool,
+    pub(crate) registry_date: String,
+    pub(crate) registry_skills: usize,
+    pub(crate) skills: Vec<UpdatedSkill>,
+}
+
+const DESCRIPTION_PREVIEW_CHARS: usize = 80;
+
+fn description_preview(description: &str, verbose: bool) -> String {
+    let first_line = description.lines().next().unwrap_or("");
+    if verbose {
+        first_line.to_string()
+    } else {
+        truncate_chars(first_line, DESCRIPTION_PREVIEW_CHARS)
+    }
+}
+
 fn coverage_suffix(covered_tools: &[String]) -> String {
     if covered_tools.is_empty() {
         String::new()
@@ -40,105 +272,285 @@ fn coverage_suffix(covered_tools: &[String]) -> String {
     }
 }
 
-fn list_skills(verbose: bool) -> Result<()> {
-    let global_config = GlobalConfig::load();
+fn install_path(skill: &Skill, tool: &str, scope: Scope) -> Option<String> {
+    let folder_name = match &skill.install_action {
+        Some(InstallAction::Npx { .. }) | Some(InstallAction::Command { .. }) => return None,
+        Some(InstallAction::MegaSkillCopy { dest_name, .. }) => dest_name.as_str(),
+        Some(InstallAction::Copy { .. }) | None => skill.name.as_str(),
+    };
+    Some(
+        get_skill_folder(tool, scope, folder_name)
+            .display()
+            .to_string(),
+    )
+}
+
+fn install_outcomes(results: &[InstallResult], skill: &Skill, scope: Scope) -> Vec<ToolOutcome> {
+    results
+        .iter()
+        .map(|r| ToolOutcome::from_install(r, install_path(skill, &r.tool, scope)))
+        .collect()
+}
+
+fn uninstall_outcomes(results: &[UninstallResult], id: &str, scope: Scope) -> Vec<ToolOutcome> {
+    results
+        .iter()
+        .map(|r| {
+            let path = get_skill_folder(&r.tool, scope, id).display().to_string();
+            ToolOutcome::from_uninstall(r, Some(path))
+        })
+        .collect()
+}
+
+pub(crate) fn collect_installed_skills(
+    global_config: &GlobalConfig,
+    project_config: Option<&ProjectConfig>,
+) -> SkillListReport {
+    let mut global: Vec<ListedSkill> = global_config
+        .list_all_skills()
+        .into_iter()
+        .map(|(tool, id, info)| ListedSkill::new(id, Some(tool), info))
+        .collect();
+    global.sort_by(|a, b| a.id.cmp(&b.id).then_with(|| a.tool.cmp(&b.tool)));
+
+    let mut project: Vec<ListedSkill> = project_config
+        .map(|c| c.list_skills())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(id, info)| ListedSkill::new(id, None, info))
+        .collect();
+    project.sort_by(|a, b| a.id.cmp(&b.id));
+
+    SkillListReport { global, project }
+}
+
+/// Flags installed skills that the registry has since deprecated.
+pub(crate) fn mark_deprecated(report: &mut SkillListReport, registry: &Registry) {
+    for listed in report.global.iter_mut().chain(report.project.iter_mut()) {
+        if let Some(skill) = registry.get_skill(&listed.id) {
+            listed.deprecated = skill.deprecated;
+            listed.superseded_by = skill.superseded_by.clone();
+        }
+    }
+}
+
+fn deprecation_suffix(deprecated: bool, superseded_by: Option<&str>) -> String {
+    deprecation_notice(deprecated, superseded_by)
+        .map(|notice| format!(" [{}]", notice))
+        .unwrap_or_default()
+}
+
+/// Records, for each project skill, whether each configured tool has it on
+/// disk and whether the installed commit still matches the registry. Tools
+/// covered through another agent (see `resolve_pi_coverage`) are skipped
+/// since nothing is installed for them.
+pub(crate) fn mark_deployment(
+    report: &mut SkillListReport,
+    tools: &[String],
+    registry: &Registry,
+    on_disk: impl Fn(&str, &str) -> bool,
+) {
+    for listed in &mut report.project {
+        let stale = registry.get_skill(&listed.id).is_some_and(|skill| {
+            !skill.commit_sha.is_empty() && skill.commit_sha != listed.commit_sha
+        });
+        listed.deployment = tools
+            .iter()
+            .filter(|tool| !listed.covered_tools.contains(tool))
+            .map(|tool| {
+                let status = match (on_disk(tool, &listed.id), stale) {
+                    (false, _) => DeploymentStatus::Missing,
+                    (true, true) => DeploymentStatus::Stale,
+                    (true, false) => DeploymentStatus::Current,
+                };
+                ToolDeployment {
+                    tool: tool.clone(),
+                    status,
+                }
+            })
+            .collect();
+    }
+}
+
+fn deployment_suffix(deployment: &[ToolDeployment]) -> String {
+    deployment
+        .iter()
+        .map(|d| format!(" [{} {}]", d.tool, d.status.marker()))
+        .collect()
+}
+
+/// Distinct installed ids across both scopes, sorted, for porcelain output.
+pub(crate) fn installed_ids(report: &SkillListReport) -> Vec<&str> {
+    report
+        .global
+        .iter()
+        .chain(&report.project)
+        .map(|s| s.id.as_str())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn list_skills(
+    verbose: bool,
+    deployed: bool,
+    porcelain: PorcelainArgs,
+    out: &Output,
+) -> Result<()> {
+    let global_config = GlobalConfig::load()?;
     let project_config_path = Path::new(".rulesify.toml");
 
     let project_config = load_project_config(project_config_path)?;
 
-    let global_skills = global_config.list_all_skills();
-    let project_skills = project_config
-        .as_ref()
-        .map(|c| c.list_skills())
-        .unwrap_or_default();
+    let mut report = collect_installed_skills(&global_config, project_config.as_ref());
+    if let Some(terminator) = porcelain.terminator() {
+        return write_porcelain(
+            &mut std::io::stdout().lock(),
+            installed_ids(&report),
+            terminator,
+        );
+    }
+    let registry = load_builtin()?;
+    mark_deprecated(&mut report, &registry);
+    if deployed {
+        if let Some(config) = &project_config {
+            mark_deployment(&mut report, &config.tools, &registry, |tool, id| {
+                skill_exists_on_disk(tool, Scope::Project, id)
+            });
+        }
+    }
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+
+    render_skill_list(&report, verbose);
+    Ok(())
+}
+
+fn dates_label(skill: &ListedSkill) -> String {
+    match &skill.updated {
+        Some(updated) => format!("added: {}, updated: {}", skill.added, updated),
+        None => format!("added: {}", skill.added),
+    }
+}
 
-    if global_skills.is_empty() && project_skills.is_empty() {
+fn render_skill_list(report: &SkillListReport, verbose: bool) {
+    if report.global.is_empty() && report.project.is_empty() {
         println!("No skills installed.");
         println!("Run `rulesify init` for project setup, or `rulesify skill add <id> --global` for global skills.");
-        return Ok(());
+        return;
     }
 
-    if !global_skills.is_empty() {
+    if !report.global.is_empty() {
         println!("Global skills:");
-        for (tool, id, info) in global_skills {
+        for skill in &report.global {
             println!(
-                "  - {} [{}] (added: {}){}",
-                id,
-                tool,
-                info.added,
-                coverage_suffix(&info.covered_tools)
+                "  - {} [{}] ({}){}{}",
+                skill.id,
+                skill.tool.as_deref().unwrap_or(""),
+                dates_label(skill),
+                coverage_suffix(&skill.covered_tools),
+                deprecation_suffix(skill.deprecated, skill.superseded_by.as_deref())
             );
             if verbose {
-                println!("    Source: {}", info.source);
+                println!("    Source: {}", skill.source);
             }
         }
     }
 
-    if !project_skills.is_empty() {
+    if !report.project.is_empty() {
         println!("\nProject skills:");
-        for (id, info) in project_skills {
+        for skill in &report.project {
             println!(
-                "  - {} (added: {}){}",
-                id,
-                info.added,
-                coverage_suffix(&info.covered_tools)
+                "  - {} ({}){}{}{}",
+                skill.id,
+                dates_label(skill),
+                coverage_suffix(&skill.covered_tools),
+                deprecation_suffix(skill.deprecated, skill.superseded_by.as_deref()),
+                deployment_suffix(&skill.deployment)
             );
             if verbose {
-                println!("    Source: {}", info.source);
-                if !info.covered_tools.is_empty() {
-                    println!("    Covered tools: {}", info.covered_tools.join(", "));
+                println!("    Source: {}", skill.source);
+                if !skill.covered_tools.is_empty() {
+                    println!("    Covered tools: {}", skill.covered_tools.join(", "));
                 }
             }
         }
     }
+}
 
-    Ok(())
+pub(crate) fn find_skills(registry: &Registry, query: Option<&str>) -> Vec<SearchHit> {
+    let query = query.map(|q| q.to_lowercase());
+
+    let mut hits: Vec<SearchHit> = registry
+        .skills
+        .iter()
+        .filter(|(_, skill)| match &query {
+            Some(q) => {
+                skill.name.to_lowercase().contains(q)
+                    || skill.description.to_lowercase().contains(q)
+            }
+            None => true,
+        })
+        .map(|(id, skill)| SearchHit::new(id, skill))
+        .collect();
+    hits.sort_by(|a, b| a.id.cmp(&b.id));
+    hits
 }
 
-fn search_skills(query: Option<String>, verbose: bool) -> Result<()> {
+fn search_skills(
+    query: Option<String>,
+    verbose: bool,
+    porcelain: PorcelainArgs,
+    out: &Output,
+) -> Result<()> {
     let registry = load_builtin()?;
 
-    let skills: Vec<_> = if let Some(q) = query {
-        registry
-            .skills
-            .iter()
-            .filter(|(_, skill)| {
-                skill.name.to_lowercase().contains(&q.to_lowercase())
-                    || skill.description.to_lowercase().contains(&q.to_lowercase())
-            })
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect()
-    } else {
-        registry
-            .skills
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect()
-    };
+    let hits = find_skills(&registry, query.as_deref());
+
+    if let Some(terminator) = porcelain.terminator() {
+        let ids = hits.iter().map(|hit| hit.id.as_str());
+        return write_porcelain(&mut std::io::stdout().lock(), ids, terminator);
+    }
 
-    if skills.is_empty() {
+    if out.is_json() {
+        return out.json(&hits);
+    }
+
+    render_search_results(&hits, verbose);
+    Ok(())
+}
+
+fn render_search_results(hits: &[SearchHit], verbose: bool) {
+    if hits.is_empty() {
         println!("No skills found.");
-        return Ok(());
+        return;
     }
 
-    println!("Available skills ({} total):\n", skills.len());
+    println!("Available skills ({} total):\n", hits.len());
 
-    let mut mega_skills: Vec<_> = skills.iter().filter(|(_, s)| s.is_mega_skill).collect();
-    mega_skills.sort_by(|a, b| b.1.stars.cmp(&a.1.stars));
+    let mut mega_skills: Vec<_> = hits.iter().filter(|s| s.is_mega_skill).collect();
+    mega_skills.sort_by_key(|s| std::cmp::Reverse(s.stars));
 
-    let mut regular_skills: Vec<_> = skills.iter().filter(|(_, s)| !s.is_mega_skill).collect();
-    regular_skills.sort_by(|a, b| b.1.name.cmp(&a.1.name));
+    let mut regular_skills: Vec<_> = hits.iter().filter(|s| !s.is_mega_skill).collect();
+    regular_skills.sort_by(|a, b| b.name.cmp(&a.name));
 
     if !mega_skills.is_empty() {
         println!("Mega-Skills (skill collections):");
-        for (id, skill) in mega_skills {
+        for skill in mega_skills {
             let score_text = skill
                 .score
                 .map(|s| format!("{:.0}", s))
                 .unwrap_or_else(|| "-".to_string());
-            println!("  [M] {} - {}", skill.name, skill.description);
+            println!(
+                "  [M] {} - {}{}",
+                skill.name,
+                description_preview(&skill.description, verbose),
+                deprecation_suffix(skill.deprecated, skill.superseded_by.as_deref())
+            );
             if verbose {
-                println!("      ID: {}", id);
+                println!("      ID: {}", skill.id);
                 println!("      Stars: ★{}", skill.stars);
                 println!("      Score: {}", score_text);
                 println!("      Source: {}", skill.source_url);
@@ -148,18 +560,19 @@ fn search_skills(query: Option<String>, verbose: bool) -> Result<()> {
     }
 
     println!("Regular Skills:");
-    for (id, skill) in regular_skills {
+    for skill in regular_skills {
         let score_text = skill
             .score
             .map(|s| format!("{:.0}", s))
             .unwrap_or_else(|| "-".to_string());
         println!(
-            "  {} - {}",
+            "  {} - {}{}",
             skill.name,
-            skill.description.lines().next().unwrap_or("")
+            description_preview(&skill.description, verbose),
+            deprecation_suffix(skill.deprecated, skill.superseded_by.as_deref())
         );
         if verbose {
-            println!("      ID: {}", id);
+            println!("      ID: {}", skill.id);
             println!("      Domain: {}", skill.domain);
             println!("      Stars: ★{}", skill.stars);
             println!("      Score: {}", score_text);
@@ -169,38 +582,91 @@ fn search_skills(query: Option<String>, verbose: bool) -> Result<()> {
 
     println!("\nTo install: rulesify skill add <id>");
     println!("For mega-skills: rulesify skill add <name> --global");
+}
+
+async fn add_skill(
+    id: String,
+    global: bool,
+    agent_mode: bool,
+    _verbose: bool,
+    out: &Output,
+) -> Result<()> {
+    let report = install_skill_by_id(&id, global, agent_mode, out).await?;
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+
+    if let Some(instructions) = &report.instructions {
+        println!("{}", instructions);
+    }
+    Ok(())
+}
 
+async fn add_group(group: &str, global: bool, agent_mode: bool, out: &Output) -> Result<()> {
+    let global_config = GlobalConfig::load()?;
+    let ids = crate::cli::group::group_skills(&global_config, group)?.to_vec();
+    // Fail before installing anything rather than leaving the group half added.
+    crate::cli::group::check_skill_ids(&load_builtin()?, &ids)?;
+
+    let mut reports = Vec::new();
+    for id in &ids {
+        reports.push(install_skill_by_id(id, global, agent_mode, out).await?);
+    }
+
+    if out.is_json() {
+        return out.json(&reports);
+    }
+
+    for report in &reports {
+        if let Some(instructions) = &report.instructions {
+            println!("{}", instructions);
+        }
+    }
     Ok(())
 }
 
-async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -> Result<()> {
+async fn install_skill_by_id(
+    id: &str,
+    global: bool,
+    agent_mode: bool,
+    out: &Output,
+) -> Result<SkillActionReport> {
     let scope = if global {
         Scope::Global
     } else {
         Scope::Project
     };
 
-    let global_config = GlobalConfig::load();
+    let global_config = GlobalConfig::load()?;
     let project_config_path = Path::new(".rulesify.toml");
 
-    if !agent_mode && global_config.is_skill_installed_globally(&id) {
-        let tools = global_config.get_tools_for_skill(&id);
-        println!(
+    if !agent_mode && global_config.is_skill_installed_globally(id) {
+        let tools = global_config.get_tools_for_skill(id);
+        out.warn(format!(
             "'{}' is already installed globally for: {}",
             id,
             tools.join(", ")
-        );
+        ));
         if !global {
-            println!("Skipping project-level installation to avoid duplication.");
+            out.status("Skipping project-level installation to avoid duplication.");
         }
-        return Ok(());
+        return Ok(SkillActionReport::new(
+            id,
+            scope,
+            ActionStatus::AlreadyGlobal,
+        ));
     }
 
     if !agent_mode && !global {
         if let Some(project_config) = load_project_config(project_config_path)? {
-            if project_config.installed_skills.contains_key(&id) {
-                println!("'{}' is already installed at project level.", id);
-                return Ok(());
+            if project_config.installed_skills.contains_key(id) {
+                out.warn(format!("'{}' is already installed at project level.", id));
+                return Ok(SkillActionReport::new(
+                    id,
+                    scope,
+                    ActionStatus::AlreadyInstalled,
+                ));
             }
         }
     }
@@ -220,12 +686,17 @@ async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -
     let registry = load_registry().await?;
 
     let skill = registry
-        .get_skill(&id)
-        .ok_or_else(|| RulesifyError::SkillNotFound(id.clone()))?;
+        .get_skill(id)
+        .ok_or_else(|| RulesifyError::SkillNotFound(id.to_string()))?;
+
+    if let Some(notice) = skill.deprecation_notice() {
+        out.warn(format!("'{}' is {}.", id, notice));
+    }
 
     if agent_mode {
-        output_install_instructions(skill, &tools, scope);
-        return Ok(());
+        let mut report = SkillActionReport::new(id, scope, ActionStatus::Instructions);
+        report.instructions = Some(install_instructions(skill, &tools, scope));
+        return Ok(report);
     }
 
     let missing_deps = check_all_dependencies(&skill.dependencies);
@@ -238,12 +709,12 @@ async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -
     }
 
     if !covered_tools.is_empty() {
-        println!(
-            "Pi is covered by other agents — skipping physical install for pi, marking in registry."
+        out.status(
+            "Pi is covered by other agents — skipping physical install for pi, marking in registry.",
         );
     }
 
-    println!("Installing '{}'...", skill.name);
+    out.status(format!("Installing '{}'...", skill.name));
 
     let results = match &skill.install_action {
         Some(InstallAction::Npx {
@@ -280,14 +751,14 @@ async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -
             .await?
         }
         Some(InstallAction::Command { value }) => {
-            println!("Running custom install command: {}", value);
+            out.status(format!("Running custom install command: {}", value));
             // Still register covered tool entries
             if global {
-                let mut global_config = GlobalConfig::load();
+                let mut global_config = GlobalConfig::load()?;
                 for tool in &physical_tools {
                     global_config.add_skill(
                         tool,
-                        &id,
+                        id,
                         &skill.source_url,
                         &skill.commit_sha,
                         covered_tools.clone(),
@@ -295,11 +766,15 @@ async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -
                 }
                 global_config.save()?;
             }
-            return Ok(());
+            let mut report = SkillActionReport::new(id, scope, ActionStatus::CustomCommand);
+            report.covered_tools = covered_tools;
+            return Ok(report);
         }
     };
 
-    print_install_summary(&results, &skill.name);
+    if !out.is_json() {
+        print_install_summary(&results, &skill.name, &out.palette);
+    }
 
     let success_count = results.iter().filter(|r| r.success).count();
     if success_count == 0 {
@@ -311,12 +786,12 @@ async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -
     }
 
     if global {
-        let mut global_config = GlobalConfig::load();
+        let mut global_config = GlobalConfig::load()?;
         for tool in &physical_tools {
             if results.iter().any(|r| r.tool == *tool && r.success) {
                 global_config.add_skill(
                     tool,
-                    &id,
+                    id,
                     &skill.source_url,
                     &skill.commit_sha,
                     covered_tools.clone(),
@@ -324,33 +799,30 @@ async fn add_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -
             }
         }
         global_config.save()?;
-        println!(
+        out.status(format!(
             "Saved global config to {}",
             get_global_config_path().display()
-        );
+        ));
     } else {
         let mut project_config = project_config.unwrap_or(ProjectConfig::new());
         project_config.add_skill(
-            &id,
+            id,
             &skill.source_url,
             &skill.commit_sha,
             Scope::Project,
             covered_tools.clone(),
         );
-        std::fs::write(
-            project_config_path,
-            toml::to_string_pretty(&project_config)?,
-        )?;
+        project_config.save(project_config_path)?;
     }
 
-    Ok(())
+    let mut report = SkillActionReport::new(id, scope, ActionStatus::Installed);
+    report.tools = install_outcomes(&results, skill, scope);
+    report.covered_tools = covered_tools;
+    Ok(report)
 }
 
-fn output_install_instructions(skill: &crate::models::Skill, tools: &[String], scope: Scope) {
-    println!(
-        "{}",
-        generate_install_instructions(&skill.name, &skill.source_url, tools, scope)
-    );
+fn install_instructions(skill: &Skill, tools: &[String], scope: Scope) -> String {
+    let mut output = generate_install_instructions(&skill.name, &skill.source_url, tools, scope);
 
     if let Some(InstallAction::Npx {
         package,
@@ -358,129 +830,200 @@ fn output_install_instructions(skill: &crate::models::Skill, tools: &[String], s
         uninstall_flag,
     }) = &skill.install_action
     {
-        println!("\n## Npx Install (GSD)");
-        println!("\nRun the following command:");
+        output.push_str("\n\n## Npx Install (GSD)\n");
+        output.push_str("\nRun the following command:\n");
         let scope_flag = match scope {
             Scope::Global => "--global",
             Scope::Project => "--local",
         };
-        println!(
-            "  npx {} {} --<tool> {}",
+        output.push_str(&format!(
+            "  npx {} {} --<tool> {}\n",
             package,
             args.join(" "),
             scope_flag
-        );
+        ));
         if let Some(flag) = uninstall_flag {
-            println!("\nTo uninstall:");
-            println!(
-                "  npx {} {} {} --<tool> {}",
+            output.push_str("\nTo uninstall:\n");
+            output.push_str(&format!(
+                "  npx {} {} {} --<tool> {}\n",
                 package,
                 args.join(" "),
                 flag,
                 scope_flag
-            );
+            ));
         }
     }
+
+    output
+}
+
+fn remove_skill(
+    id: String,
+    global: bool,
+    agent_mode: bool,
+    _verbose: bool,
+    out: &Output,
+) -> Result<()> {
+    let report = uninstall_skill_by_id(&id, global, agent_mode, out)?;
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+
+    if let Some(instructions) = &report.instructions {
+        println!("{}", instructions);
+    }
+    Ok(())
 }
 
-fn remove_skill(id: String, global: bool, agent_mode: bool, _verbose: bool) -> Result<()> {
+fn uninstall_skill_by_id(
+    id: &str,
+    global: bool,
+    agent_mode: bool,
+    out: &Output,
+) -> Result<SkillActionReport> {
     let scope = if global {
         Scope::Global
     } else {
         Scope::Project
     };
 
-    let global_config = GlobalConfig::load();
+    let global_config = GlobalConfig::load()?;
     let project_config_path = Path::new(".rulesify.toml");
 
     if global {
         // `get_tools_for_skill` returns only tools with direct entries
         // (not covered tools), which is the correct set for physical uninstall.
-        let tools = global_config.get_tools_for_skill(&id);
+        let tools = global_config.get_tools_for_skill(id);
         if tools.is_empty() {
-            println!("'{}' is not installed globally.", id);
-            return Ok(());
+            out.warn(format!("'{}' is not installed globally.", id));
+            return Ok(SkillActionReport::new(
+                id,
+                scope,
+                ActionStatus::NotInstalled,
+            ));
         }
 
         if agent_mode {
-            println!("{}", generate_uninstall_instructions(&id, &tools, scope));
-            return Ok(());
+            let mut report = SkillActionReport::new(id, scope, ActionStatus::Instructions);
+            report.instructions = Some(generate_uninstall_instructions(id, &tools, scope));
+            return Ok(report);
         }
 
-        let results = uninstall_skill(&id, &tools, scope);
+        let results = uninstall_skill(id, &tools, scope);
 
-        print_uninstall_summary(&results, &id);
+        if !out.is_json() {
+            print_uninstall_summary(&results, id, &out.palette);
+        }
 
-        let mut global_config = GlobalConfig::load();
+        let mut global_config = GlobalConfig::load()?;
         for tool in &tools {
-            global_config.remove_skill(tool, &id);
+            global_config.remove_skill(tool, id);
         }
         global_config.save()?;
+
+        let mut report = SkillActionReport::new(id, scope, ActionStatus::Removed);
+        report.tools = uninstall_outcomes(&results, id, scope);
+        Ok(report)
     } else {
         let project_config = load_project_config(project_config_path)?
             .ok_or_else(|| RulesifyError::ConfigNotFound)?;
 
-        if !project_config.installed_skills.contains_key(&id) {
-            println!("'{}' is not installed at project level.", id);
-            return Ok(());
+        if !project_config.installed_skills.contains_key(id) {
+            out.warn(format!("'{}' is not installed at project level.", id));
+            return Ok(SkillActionReport::new(
+                id,
+                scope,
+                ActionStatus::NotInstalled,
+            ));
         }
 
         if agent_mode {
-            println!(
-                "{}",
-                generate_uninstall_instructions(&id, &project_config.tools, scope)
-            );
-            return Ok(());
+            let mut report = SkillActionReport::new(id, scope, ActionStatus::Instructions);
+            report.instructions = Some(generate_uninstall_instructions(
+                id,
+                &project_config.tools,
+                scope,
+            ));
+            return Ok(report);
         }
 
         // Resolve Pi coverage: only delete physical installs.
         // Covered tools (e.g. Pi) have no files to clean up.
         let (physical_tools, _) = resolve_pi_coverage(&project_config.tools);
 
-        let results = uninstall_skill(&id, &physical_tools, scope);
+        let results = uninstall_skill(id, &physical_tools, scope);
 
-        print_uninstall_summary(&results, &id);
+        if !out.is_json() {
+            print_uninstall_summary(&results, id, &out.palette);
+        }
 
         let mut project_config = project_config;
-        project_config.remove_skill(&id);
-        std::fs::write(
-            project_config_path,
-            toml::to_string_pretty(&project_config)?,
-        )?;
+        project_config.remove_skill(id);
+        project_config.save(project_config_path)?;
+
+        let mut report = SkillActionReport::new(id, scope, ActionStatus::Removed);
+        report.tools = uninstall_outcomes(&results, id, scope);
+        Ok(report)
     }
+}
+
+async fn update_directory_registry(
+    agent_mode: bool,
+    force: bool,
+    verbose: bool,
+    out: &Output,
+) -> Result<()> {
+    let report = update_registry_and_skills(agent_mode, force, verbose, out).await?;
 
+    if out.is_json() {
+        return out.json(&report);
+    }
     Ok(())
 }
 
-async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool) -> Result<()> {
+async fn update_registry_and_skills(
+    agent_mode: bool,
+    force: bool,
+    verbose: bool,
+    out: &Output,
+) -> Result<UpdateReport> {
+    let mut report = UpdateReport::default();
+
     // 1. Check local registry.toml date
     let local_path = Path::new("registry.toml");
     let local_updated = if local_path.exists() {
         let content = std::fs::read_to_string(local_path)?;
-        let local: Registry = toml::from_str(&content)?;
+        let local: Registry = parse_toml_file(local_path, &content)?;
         local.updated
     } else {
         String::new()
     };
 
     // 2. Fetch remote registry
-    println!("Fetching remote registry...");
+    out.status("Fetching remote registry...");
     let registry = fetch_registry().await?;
+    report.registry_date = registry.updated.clone();
+    report.registry_skills = registry.skills.len();
 
     // 3. Compare dates — skip if local is already current
     if needs_registry_update(force, &local_updated, &registry.updated) {
         if force {
-            println!("Force updating local registry...");
+            out.status("Force updating local registry...");
         } else {
-            println!(
+            out.status(format!(
                 "Updating local registry ({} \u{2192} {})...",
                 local_updated, registry.updated
-            );
+            ));
         }
 
         let content = toml::to_string_pretty(&registry)?;
-        std::fs::write(local_path, content)?;
-        println!("Local registry updated ({} skills)", registry.skills.len());
+        atomic_write(local_path, content)?;
+        report.registry_updated = true;
+        out.status(format!(
+            "Local registry updated ({} skills)",
+            registry.skills.len()
+        ));
     }
 
     // 4. Save to local cache (always, so installed-skill update can use it)
@@ -488,21 +1031,21 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
     cache.save(&registry)?;
 
     if verbose {
-        println!("Updated date: {}", registry.updated);
+        out.status(format!("Updated date: {}", registry.updated));
     }
 
     if agent_mode {
-        println!("\nTo update installed skills, run:");
-        println!("  rulesify skill update");
-        return Ok(());
+        out.status("\nTo update installed skills, run:");
+        out.status("  rulesify skill update");
+        return Ok(report);
     }
 
-    let global_config = GlobalConfig::load();
+    let global_config = GlobalConfig::load()?;
     let project_config_path = Path::new(".rulesify.toml");
     let project_config = load_project_config(project_config_path)?;
 
-    let mut global_updated: Vec<(String, String, crate::models::Skill)> = vec![];
-    let mut project_updated: Vec<(String, crate::models::Skill)> = vec![];
+    let mut global_updated: Vec<(String, String, Skill)> = vec![];
+    let mut project_updated: Vec<(String, Skill)> = vec![];
 
     for (tool, id, info) in global_config.list_all_skills() {
         if let Some(skill) = registry.get_skill(&id) {
@@ -523,25 +1066,25 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
     }
 
     if global_updated.is_empty() && project_updated.is_empty() {
-        println!("No installed skills need updates.");
-        return Ok(());
+        out.status("No installed skills need updates.");
+        return Ok(report);
     }
 
-    println!(
+    out.status(format!(
         "\n{} global skills, {} project skills have updates:",
         global_updated.len(),
         project_updated.len()
-    );
+    ));
 
     for (tool, id, skill) in &global_updated {
         let old_sha = &global_config
             .get_skill_for_tool(tool, id)
             .unwrap()
             .commit_sha;
-        println!(
+        out.status(format!(
             "  - {} [{}] (global: {} → {})",
             id, tool, old_sha, skill.commit_sha
-        );
+        ));
     }
 
     for (id, skill) in &project_updated {
@@ -552,14 +1095,20 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
             .get(id)
             .unwrap()
             .commit_sha;
-        println!("  - {} (project: {} → {})", id, old_sha, skill.commit_sha);
+        out.status(format!(
+            "  - {} (project: {} → {})",
+            id, old_sha, skill.commit_sha
+        ));
     }
 
     let archive_cache = ArchiveCache::new();
     let client = GitHubClient::new();
 
-    for (tool, _id, skill) in &global_updated {
-        println!("\nUpdating '{}' [{}] (global)...", skill.name, tool);
+    for (tool, id, skill) in &global_updated {
+        out.status(format!(
+            "\nUpdating '{}' [{}] (global)...",
+            skill.name, tool
+        ));
 
         let results = match &skill.install_action {
             Some(InstallAction::Npx {
@@ -599,7 +1148,20 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
                 .await?
             }
         };
-        print_install_summary(&results, &skill.name);
+        if !out.is_json() {
+            print_install_summary(&results, &skill.name, &out.palette);
+        }
+        report.skills.push(UpdatedSkill {
+            id: id.clone(),
+            tool: Some(tool.clone()),
+            scope: Scope::Global,
+            old_sha: global_config
+                .get_skill_for_tool(tool, id)
+                .map(|s| s.commit_sha.clone())
+                .unwrap_or_default(),
+            new_sha: skill.commit_sha.clone(),
+            tools: install_outcomes(&results, skill, Scope::Global),
+        });
     }
 
     if !project_updated.is_empty() {
@@ -608,8 +1170,8 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
         };
         let tools = config.tools.clone();
         let (physical_tools, _) = resolve_pi_coverage(&tools);
-        for (_id, skill) in &project_updated {
-            println!("\nUpdating '{}' (project)...", skill.name);
+        for (id, skill) in &project_updated {
+            out.status(format!("\nUpdating '{}' (project)...", skill.name));
 
             let results = match &skill.install_action {
                 Some(InstallAction::Npx {
@@ -649,11 +1211,25 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
                     .await?
                 }
             };
-            print_install_summary(&results, &skill.name);
+            if !out.is_json() {
+                print_install_summary(&results, &skill.name, &out.palette);
+            }
+            report.skills.push(UpdatedSkill {
+                id: id.clone(),
+                tool: None,
+                scope: Scope::Project,
+                old_sha: config
+                    .installed_skills
+                    .get(id)
+                    .map(|s| s.commit_sha.clone())
+                    .unwrap_or_default(),
+                new_sha: skill.commit_sha.clone(),
+                tools: install_outcomes(&results, skill, Scope::Project),
+            });
         }
     }
 
-    let mut global_config = GlobalConfig::load();
+    let mut global_config = GlobalConfig::load()?;
     for (tool, id, skill) in &global_updated {
         global_config.update_skill_sha(tool, id, &skill.commit_sha);
     }
@@ -663,10 +1239,10 @@ async fn update_directory_registry(agent_mode: bool, force: bool, verbose: bool)
         for (id, skill) in &project_updated {
             config.update_skill_sha(id, &skill.commit_sha);
         }
-        std::fs::write(project_config_path, toml::to_string_pretty(&config)?)?;
+        config.save(project_config_path)?;
     }
 
-    Ok(())
+    Ok(report)
 }
 
 async fn load_registry() -> Result<Registry> {
@@ -719,4 +1295,157 @@ mod tests {
         assert!(needs_registry_update(true, "2026-06-15", "2026-06-15"));
         assert!(needs_registry_update(true, "", "2026-06-15"));
     }
+
+    #[test]
+    fn test_collect_installed_skills_is_sorted() {
+        let mut global_config = GlobalConfig::new();
+        global_config.add_skill("codex", "zeta", "https://example.com", "abc", vec![]);
+        global_config.add_skill("claude-code", "alpha", "https://example.com", "abc", vec![]);
+        let mut project_config = ProjectConfig::new();
+        project_config.add_skill("b", "https://example.com", "abc", Scope::Project, vec![]);
+        project_config.add_skill("a", "https://example.com", "abc", Scope::Project, vec![]);
+
+        let report = collect_installed_skills(&global_config, Some(&project_config));
+
+        let global_ids: Vec<_> = report.global.iter().map(|s| s.id.as_str()).collect();
+        let project_ids: Vec<_> = report.project.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(global_ids, vec!["alpha", "zeta"]);
+        assert_eq!(project_ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_skill_list_report_json_shape() {
+        let mut global_config = GlobalConfig::new();
+        global_config.add_skill("codex", "tdd", "https://example.com", "abc", vec![]);
+
+        let report = collect_installed_skills(&global_config, None);
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["global"][0]["id"], "tdd");
+        assert_eq!(json["global"][0]["tool"], "codex");
+        assert_eq!(json["global"][0]["scope"], "global");
+        assert!(json["project"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_find_skills_filters_by_query() {
+        let registry = load_builtin().unwrap();
+        let hits = find_skills(&registry, Some("zzz-no-such-skill-zzz"));
+        assert!(hits.is_empty());
+
+        let all = find_skills(&registry, None);
+        assert_eq!(all.len(), registry.skills.len());
+    }
+
+    #[test]
+    fn test_action_report_serializes_status_snake_case() {
+        let report = SkillActionReport::new("tdd", Scope::Project, ActionStatus::AlreadyGlobal);
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["status"], "already_global");
+        assert_eq!(json["scope"], "project");
+        assert!(json.get("tools").is_none());
+    }
+
+    #[test]
+    fn test_mark_deprecated_flags_installed_skills() {
+        let mut registry = load_builtin().unwrap();
+        let id = registry.skills.keys().next().unwrap().clone();
+        let skill = registry.skills.get_mut(&id).unwrap();
+        skill.deprecated = true;
+        skill.superseded_by = Some("replacement".to_string());
+
+        let mut project_config = ProjectConfig::new();
+        project_config.add_skill(&id, "https://example.com", "abc", Scope::Project, vec![]);
+        project_config.add_skill(
+            "not-in-registry",
+            "https://example.com",
+            "abc",
+            Scope::Project,
+            vec![],
+        );
+        let mut report = collect_installed_skills(&GlobalConfig::new(), Some(&project_config));
+
+        mark_deprecated(&mut report, &registry);
+
+        let flagged = report.project.iter().find(|s| s.id == id).unwrap();
+        assert!(flagged.deprecated);
+        assert_eq!(flagged.superseded_by.as_deref(), Some("replacement"));
+        let json = serde_json::to_value(&report).unwrap();
+        let other = json["project"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["id"] == "not-in-registry")
+            .unwrap();
+        assert!(other.get("deprecated").is_none());
+    }
+
+    #[test]
+    fn test_mark_deployment_reports_each_physical_tool() {
+        let mut registry = load_builtin().unwrap();
+        let mut skill = registry.skills.values().next().unwrap().clone();
+        skill.commit_sha = "abc".to_string();
+        registry.skills.insert("tdd".to_string(), skill.clone());
+        skill.commit_sha = "new".to_string();
+        registry.skills.insert("lint".to_string(), skill);
+
+        let mut project_config = ProjectConfig::new();
+        for id in ["tdd", "lint"] {
+            project_config.add_skill(
+                id,
+                "https://example.com",
+                "abc",
+                Scope::Project,
+                vec!["pi".to_string()],
+            );
+        }
+        let mut report = collect_installed_skills(&GlobalConfig::new(), Some(&project_config));
+        let tools = vec!["cursor".to_string(), "codex".to_string(), "pi".to_string()];
+
+        mark_deployment(&mut report, &tools, &registry, |tool, _| tool == "cursor");
+
+        let tdd = report.project.iter().find(|s| s.id == "tdd").unwrap();
+        assert_eq!(
+            tdd.deployment,
+            vec![
+                ToolDeployment {
+                    tool: "cursor".to_string(),
+                    status: DeploymentStatus::Current
+                },
+                ToolDeployment {
+                    tool: "codex".to_string(),
+                    status: DeploymentStatus::Missing
+                },
+            ]
+        );
+        assert_eq!(deployment_suffix(&tdd.deployment), " [cursor ✓] [codex ✗]");
+
+        let lint = report.project.iter().find(|s| s.id == "lint").unwrap();
+        assert_eq!(deployment_suffix(&lint.deployment), " [cursor ~] [codex ✗]");
+        assert_eq!(
+            serde_json::to_value(&lint.deployment[0]).unwrap(),
+            serde_json::json!({ "tool": "cursor", "status": "stale" })
+        );
+    }
+
+    #[test]
+    fn test_installed_ids_are_distinct_and_sorted() {
+        let mut global_config = GlobalConfig::new();
+        global_config.add_skill("cursor", "tdd", "https://example.com", "abc", vec![]);
+        global_config.add_skill("codex", "tdd", "https://example.com", "abc", vec![]);
+        let mut project_config = ProjectConfig::new();
+        project_config.add_skill(
+            "brainstorm",
+            "https://example.com",
+            "abc",
+            Scope::Project,
+            vec![],
+        );
+        project_config.add_skill("tdd", "https://example.com", "abc", Scope::Project, vec![]);
+
+        let report = collect_installed_skills(&global_config, Some(&project_config));
+
+        assert_eq!(installed_ids(&report), vec!["brainstorm", "tdd"]);
+    }
 }
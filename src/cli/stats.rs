@@ -0,0 +1,203 @@
+use crate::cli::output::Output;
+use crate::models::{GlobalConfig, ProjectConfig, Registry, PROJECT_CONFIG_FILE};
+use crate::registry::load_builtin;
+use crate::utils::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+const TOP_TAGS: usize = 10;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct TagCount {
+    pub tag: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct ContextSizeStats {
+    pub average: u32,
+    pub median: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct StatsReport {
+    pub total: usize,
+    pub mega_skills: usize,
+    pub deprecated: usize,
+    pub domains: BTreeMap<String, usize>,
+    pub top_tags: Vec<TagCount>,
+    /// Over skills with a known context size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_size: Option<ContextSizeStats>,
+    pub missing_description: Vec<String>,
+    pub missing_tags: Vec<String>,
+    pub installed_project: usize,
+    pub installed_global: usize,
+    /// Installed ids the registry no longer knows about.
+    pub not_in_registry: Vec<String>,
+}
+
+pub fn run(out: &Output) -> Result<()> {
+    let registry = load_builtin()?;
+    let global = GlobalConfig::load()?;
+    let project = ProjectConfig::reconcile_and_load(Path::new(PROJECT_CONFIG_FILE))?;
+    let report = collect_stats(&registry, project.as_ref(), &global);
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+    render_report(&report, out);
+    Ok(())
+}
+
+pub(crate) fn collect_stats(
+    registry: &Registry,
+    project: Option<&ProjectConfig>,
+    global: &GlobalConfig,
+) -> StatsReport {
+    let mut domains = BTreeMap::new();
+    let mut tags: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut sizes = Vec::new();
+    let mut missing_description = Vec::new();
+    let mut missing_tags = Vec::new();
+
+    for (id, skill) in &registry.skills {
+        let domain = if skill.domain.is_empty() {
+            "(none)"
+        } else {
+            skill.domain.as_str()
+        };
+        *domains.entry(domain.to_string()).or_insert(0) += 1;
+        for tag in &skill.tags {
+            *tags.entry(tag.as_str()).or_insert(0) += 1;
+        }
+        if skill.context_size > 0 {
+            sizes.push(skill.context_size);
+        }
+        if skill.description.trim().is_empty() {
+            missing_description.push(id.clone());
+        }
+        if skill.tags.is_empty() {
+            missing_tags.push(id.clone());
+        }
+    }
+    missing_description.sort();
+    missing_tags.sort();
+
+    let mut top_tags: Vec<TagCount> = tags
+        .into_iter()
+        .map(|(tag, count)| TagCount {
+            tag: tag.to_string(),
+            count,
+        })
+        .collect();
+    top_tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+    top_tags.truncate(TOP_TAGS);
+
+    let project_ids: BTreeSet<&str> = project
+        .map(|p| p.installed_skills.keys().map(String::as_str).collect())
+        .unwrap_or_default();
+    let global_ids: BTreeSet<&str> = global
+        .installed_skills
+        .values()
+        .flat_map(|skills| skills.keys().map(String::as_str))
+        .collect();
+    let not_in_registry = project_ids
+        .union(&global_ids)
+        .filter(|id| registry.get_skill(id).is_none())
+        .map(|id| id.to_string())
+        .collect();
+
+    StatsReport {
+        total: registry.skills.len(),
+        mega_skills: registry.skills.values().filter(|s| s.is_mega_skill).count(),
+        deprecated: registry.skills.values().filter(|s| s.deprecated).count(),
+        domains,
+        top_tags,
+        context_size: context_size_stats(sizes),
+        missing_description,
+        missing_tags,
+        installed_project: project_ids.len(),
+        installed_global: global_ids.len(),
+        not_in_registry,
+    }
+}
+
+fn context_size_stats(mut sizes: Vec<u32>) -> Option<ContextSizeStats> {
+    if sizes.is_empty() {
+        return None;
+    }
+    sizes.sort_unstable();
+    let total: u64 = sizes.iter().map(|&s| u64::from(s)).sum();
+    let mid = sizes.len() / 2;
+    let median = if sizes.len().is_multiple_of(2) {
+        (sizes[mid - 1] + sizes[mid]) / 2
+    } else {
+        sizes[mid]
+    };
+    Some(ContextSizeStats {
+        average: (total / sizes.len() as u64) as u32,
+        median,
+    })
+}
+
+fn render_report(report: &StatsReport, out: &Output) {
+    println!(
+        "Registry: {} skills ({} mega-skills, {} deprecated)",
+        report.total, report.mega_skills, report.deprecated
+    );
+    println!(
+        "Installed: {} project, {} global",
+        report.installed_project, report.installed_global
+    );
+
+    let width = report
+        .domains
+        .keys()
+        .chain(report.top_tags.iter().map(|t| &t.tag))
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    println!();
+    println!("Domains:");
+    for (domain, count) in &report.domains {
+        println!("  {:<width$}  {}", domain, count);
+    }
+
+    if !report.top_tags.is_empty() {
+        println!();
+        println!("Top tags:");
+        for tag in &report.top_tags {
+            println!("  {:<width$}  {}", tag.tag, tag.count);
+        }
+    }
+
+    if let Some(size) = &report.context_size {
+        println!();
+        println!(
+            "Context size: {} average, {} median",
+            size.average, size.median
+        );
+    }
+
+    let gaps = [
+        ("without description", &report.missing_description),
+        ("without tags", &report.missing_tags),
+        ("installed but not in registry", &report.not_in_registry),
+    ];
+    for (label, ids) in gaps {
+        if !ids.is_empty() {
+            println!();
+            println!(
+                "{}",
+                out.palette
+                    .warn(&format!("{} skill(s) {}:", ids.len(), label))
+            );
+            for id in ids {
+                println!("  - {}", id);
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::cli::stats::{collect_stats, ContextSizeStats, TagCount};
+use crate::models::{GlobalConfig, ProjectConfig, Registry, Scope};
+
+fn sample_registry() -> Registry {
+    toml::from_str(
+        r#"
+version = 1
+updated = "2026-04-14"
+
+[skills.tdd]
+name = "tdd"
+description = "Test driven development"
+source_url = "https://github.com/example/skills/tree/main/tdd"
+stars = 10
+last_updated = "2026-01-01"
+domain = "development"
+context_size = 1000
+tags = ["testing", "workflow"]
+
+[skills.lint]
+name = "lint"
+description = ""
+source_url = "https://github.com/example/skills/tree/main/lint"
+stars = 10
+last_updated = "2026-01-01"
+domain = "development"
+context_size = 3000
+tags = ["testing"]
+
+[skills.old-docs]
+name = "old-docs"
+description = "Docs"
+source_url = "https://github.com/example/skills/tree/main/old-docs"
+stars = 10
+last_updated = "2026-01-01"
+deprecated = true
+"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_collect_stats_aggregates_registry() {
+    let report = collect_stats(&sample_registry(), None, &GlobalConfig::new());
+
+    assert_eq!(report.total, 3);
+    assert_eq!(report.deprecated, 1);
+    assert_eq!(report.domains["development"], 2);
+    assert_eq!(report.domains["(none)"], 1);
+    assert_eq!(
+        report.top_tags[0],
+        TagCount {
+            tag: "testing".to_string(),
+            count: 2
+        }
+    );
+    assert_eq!(
+        report.context_size,
+        Some(ContextSizeStats {
+            average: 2000,
+            median: 2000
+        })
+    );
+    assert_eq!(report.missing_description, vec!["lint".to_string()]);
+    assert_eq!(report.missing_tags, vec!["old-docs".to_string()]);
+}
+
+#[test]
+fn test_collect_stats_counts_installed_and_unknown_skills() {
+    let mut project = ProjectConfig::new();
+    project.add_skill("tdd", "https://example.com", "abc", Scope::Project, vec![]);
+    project.add_skill("gone", "https://example.com", "abc", Scope::Project, vec![]);
+    let mut global = GlobalConfig::new();
+    global.add_skill("cursor", "lint", "https://example.com", "abc", vec![]);
+    global.add_skill("codex", "lint", "https://example.com", "abc", vec![]);
+
+    let report = collect_stats(&sample_registry(), Some(&project), &global);
+
+    assert_eq!(report.installed_project, 2);
+    assert_eq!(report.installed_global, 1);
+    assert_eq!(report.not_in_registry, vec!["gone".to_string()]);
+}
@@ -1,9 +1,9 @@
 use crate::fetcher::ArchiveCache;
-use crate::installer::tool_paths::get_skill_folder;
+use crate::installer::tool_paths::{get_skill_folder, tool_spec};
 use crate::models::{Scope, Skill};
 use crate::registry::github::GitHubClient;
 use crate::registry::parser::SkillParser;
-use crate::utils::{Result, RulesifyError};
+use crate::utils::{Palette, Result, RulesifyError};
 use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -278,7 +278,9 @@ fn install_for_tool(
     tool: &str,
     warning: Option<String>,
 ) -> InstallResult {
+    log::debug!("Installing to {} for {}", skill_folder.display(), tool);
     if skill_folder.exists() {
+        log::debug!("Clearing existing {}", skill_folder.display());
         if let Err(e) = std::fs::remove_dir_all(skill_folder) {
             return InstallResult {
                 tool: tool.to_string(),
@@ -307,6 +309,11 @@ fn install_for_tool(
         let file_name = entry.file_name().to_string_lossy().to_string();
         let source_path = extracted_folder.join(&file_name);
         let target_path = skill_folder.join(&file_name);
+        log::trace!(
+            "Copying {} -> {}",
+            source_path.display(),
+            target_path.display()
+        );
 
         if entry.path().is_dir() {
             if let Err(e) = copy_dir_all(&source_path, &target_path) {
@@ -365,14 +372,7 @@ pub fn execute_npx_install<T: AsRef<str>>(
     let mut results = Vec::new();
 
     for tool in tools {
-        let tool_flag = match tool.as_ref() {
-            "claude-code" => "--claude",
-            "opencode" => "--opencode",
-            "cursor" => "--cursor",
-            "codex" => "--codex",
-            "pi" => "--pi",
-            _ => "",
-        };
+        let tool_flag = tool_spec(tool.as_ref()).map_or("", |spec| spec.npx_flag);
 
         let scope_flag = match scope {
             Scope::Global => "--global",
@@ -435,14 +435,7 @@ pub fn execute_npx_uninstall(
     let mut results = Vec::new();
 
     for tool in tools {
-        let tool_flag = match tool.as_str() {
-            "claude-code" => "--claude",
-            "opencode" => "--opencode",
-            "cursor" => "--cursor",
-            "codex" => "--codex",
-            "pi" => "--pi",
-            _ => "",
-        };
+        let tool_flag = tool_spec(tool.as_str()).map_or("", |spec| spec.npx_flag);
 
         let scope_flag = match scope {
             Scope::Global => "--global",
@@ -499,6 +492,7 @@ pub fn uninstall_skill(skill_name: &str, tools: &[String], scope: Scope) -> Vec<
 }
 
 fn uninstall_for_tool(skill_folder: PathBuf, tool: String) -> UninstallResult {
+    log::debug!("Removing {} for {}", skill_folder.display(), tool);
     if !skill_folder.exists() {
         return UninstallResult {
             tool,
@@ -521,7 +515,7 @@ fn uninstall_for_tool(skill_folder: PathBuf, tool: String) -> UninstallResult {
     }
 }
 
-pub fn print_install_summary(results: &[InstallResult], skill_name: &str) {
+pub fn print_install_summary(results: &[InstallResult], skill_name: &str, palette: &Palette) {
     let successful = results.iter().filter(|r| r.success).count();
     let failed = results.len() - successful;
     let warnings: BTreeSet<&str> = results
@@ -530,48 +524,72 @@ pub fn print_install_summary(results: &[InstallResult], skill_name: &str) {
         .collect();
 
     for warning in warnings {
-        println!("  ! {}", warning);
+        println!("  {}", palette.warn(&format!("! {}", warning)));
     }
 
     if failed == 0 {
         println!(
-            "Installed '{}' to {} tools ({} files each)",
-            skill_name,
-            successful,
-            results.first().map(|r| r.files_created).unwrap_or(0)
+            "{}",
+            palette.success(&format!(
+                "Installed '{}' to {} tools ({} files each)",
+                skill_name,
+                successful,
+                results.first().map(|r| r.files_created).unwrap_or(0)
+            ))
         );
     } else {
-        println!("Installed '{}' with issues:", skill_name);
+        println!(
+            "{}",
+            palette.warn(&format!("Installed '{}' with issues:", skill_name))
+        );
         for r in results {
             if r.success {
-                println!("  ✓ {}: {} files", r.tool, r.files_created);
+                println!(
+                    "  {}",
+                    palette.success(&format!("✓ {}: {} files", r.tool, r.files_created))
+                );
             } else {
                 println!(
-                    "  ✗ {}: {}",
-                    r.tool,
-                    r.error.as_deref().unwrap_or("unknown error")
+                    "  {}",
+                    palette.error(&format!(
+                        "✗ {}: {}",
+                        r.tool,
+                        r.error.as_deref().unwrap_or("unknown error")
+                    ))
                 );
             }
         }
     }
 }
 
-pub fn print_uninstall_summary(results: &[UninstallResult], skill_name: &str) {
+pub fn print_uninstall_summary(results: &[UninstallResult], skill_name: &str, palette: &Palette) {
     let successful = results.iter().filter(|r| r.folder_deleted).count();
     let failed = results.len() - successful;
 
     if failed == 0 {
-        println!("Removed '{}' from {} tools", skill_name, successful);
+        println!(
+            "{}",
+            palette.success(&format!(
+                "Removed '{}' from {} tools",
+                skill_name, successful
+            ))
+        );
     } else {
-        println!("Removed '{}' with issues:", skill_name);
+        println!(
+            "{}",
+            palette.warn(&format!("Removed '{}' with issues:", skill_name))
+        );
         for r in results {
             if r.folder_deleted {
-                println!("  ✓ {}", r.tool);
+                println!("  {}", palette.success(&format!("✓ {}", r.tool)));
             } else {
                 println!(
-                    "  ✗ {}: {}",
-                    r.tool,
-                    r.error.as_deref().unwrap_or("unknown error")
+                    "  {}",
+                    palette.error(&format!(
+                        "✗ {}: {}",
+                        r.tool,
+                        r.error.as_deref().unwrap_or("unknown error")
+                    ))
                 );
             }
         }
@@ -193,6 +193,8 @@ fn make_normal_skill(name: &str, source_url: &str) -> Skill {
         score: Some(80.0),
         is_mega_skill: false,
         dependencies: Vec::new(),
+        deprecated: false,
+        superseded_by: None,
     }
 }
 
@@ -343,6 +345,8 @@ fn make_mega_skill(name: &str, source_url: &str, source_folder: &str, dest_name:
         score: Some(90.0),
         is_mega_skill: true,
         dependencies: Vec::new(),
+        deprecated: false,
+        superseded_by: None,
     }
 }
 
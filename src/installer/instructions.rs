@@ -1,4 +1,4 @@
-use crate::installer::tool_paths::{get_skill_folder, get_skill_path};
+use crate::installer::tool_paths::{get_skill_folder, get_skill_path, get_skills_parent_dir};
 use crate::models::{InstallAction, Scope, Skill};
 
 pub fn generate_instructions(skills: &[(String, Skill)], tools: &[String]) -> String {
@@ -57,7 +57,7 @@ fn generate_mega_skill_copy_instructions(
         .source_url
         .replace("/tree/main/", "/archive/refs/heads/main.zip");
 
-    let dest_folder = format!("{}/skills/{}", get_tool_base_path(tool), dest_name);
+    let dest_folder = get_skills_parent_dir(tool).join(dest_name);
 
     format!(
         "This is a mega-skill. Install by copying the entire `{}` folder:\n\n\
@@ -66,7 +66,11 @@ fn generate_mega_skill_copy_instructions(
          3. Copy the `{}` folder to:\n   {}\n\n\
          4. Rename the copied folder to `{}`\n\n\
          Result: The destination should contain all sub-skills as subdirectories.\n\n",
-        source_folder, repo_url, source_folder, dest_folder, dest_name
+        source_folder,
+        repo_url,
+        source_folder,
+        dest_folder.display(),
+        dest_name
     )
 }
 
@@ -111,17 +115,6 @@ fn generate_command_instructions(value: &str) -> String {
     format!("Run the following command:\n\n```bash\n{}\n```\n\n", value)
 }
 
-fn get_tool_base_path(tool: &str) -> &'static str {
-    match tool {
-        "claude-code" => ".claude",
-        "codex" => ".agents",
-        "cursor" => ".cursor",
-        "opencode" => ".opencode",
-        "pi" => ".pi/skills/pi-skills",
-        _ => ".agents",
-    }
-}
-
 pub fn generate_install_instructions(
     skill_name: &str,
     source_url: &str,
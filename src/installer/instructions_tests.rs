@@ -107,6 +107,8 @@ mod tests {
             score: Some(100.0),
             is_mega_skill: true,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         };
 
         let instructions = generate_instructions(
@@ -140,6 +142,8 @@ mod tests {
             score: Some(85.0),
             is_mega_skill: true,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         };
 
         let instructions =
@@ -170,6 +174,8 @@ mod tests {
             score: Some(90.0),
             is_mega_skill: true,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         };
 
         let instructions = generate_instructions(
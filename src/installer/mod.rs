@@ -18,7 +18,10 @@ pub use instructions::{
     generate_install_instructions, generate_instructions, generate_uninstall_instructions,
     generate_uninstall_instructions_batch,
 };
-pub use tool_paths::{get_skill_folder, get_skill_path};
+pub use tool_paths::{
+    canonical_tool, get_skill_folder, get_skill_path, is_supported_tool, resolve_tool, tool_spec,
+    ToolSpec, SUPPORTED_TOOLS, TOOL_SPECS,
+};
 
 /// Given a list of tools, returns `(physical_install_tools, covered_tools)`.
 ///
@@ -1,34 +1,110 @@
 use crate::models::Scope;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
+/// Everything rulesify needs to know about one AI tool.
+#[derive(Debug)]
+pub struct ToolSpec {
+    pub name: &'static str,
+    /// Alternative spellings accepted on the command line and in configs.
+    pub aliases: &'static [&'static str],
+    /// Skills directory relative to the project root.
+    pub project_skills_dir: &'static str,
+    /// Skills directory relative to the home directory.
+    pub global_skills_dir: &'static str,
+    /// Flag passed to npx-based installers to target this tool.
+    pub npx_flag: &'static str,
+    /// Files or directories whose presence means the project uses this tool.
+    pub markers: &'static [&'static str],
+}
+
+/// The single table of tools rulesify knows how to install skills for.
+pub const TOOL_SPECS: [ToolSpec; 5] = [
+    ToolSpec {
+        name: "claude-code",
+        aliases: &["claude_code", "claudecode", "claude"],
+        project_skills_dir: ".claude/skills",
+        global_skills_dir: ".claude/skills",
+        npx_flag: "--claude",
+        markers: &["CLAUDE.md", ".claude"],
+    },
+    ToolSpec {
+        name: "codex",
+        aliases: &[],
+        project_skills_dir: ".agents/skills",
+        global_skills_dir: ".agents/skills",
+        npx_flag: "--codex",
+        markers: &[".agents", "AGENTS.md"],
+    },
+    ToolSpec {
+        name: "cursor",
+        aliases: &[],
+        project_skills_dir: ".cursor/skills",
+        global_skills_dir: ".cursor/skills",
+        npx_flag: "--cursor",
+        markers: &[".cursor", ".cursorrules"],
+    },
+    ToolSpec {
+        name: "opencode",
+        aliases: &["open-code", "open_code"],
+        project_skills_dir: ".opencode/skills",
+        global_skills_dir: ".config/opencode/skills",
+        npx_flag: "--opencode",
+        markers: &[".opencode"],
+    },
+    ToolSpec {
+        name: "pi",
+        aliases: &[],
+        project_skills_dir: ".pi/skills/pi-skills",
+        global_skills_dir: ".pi/agent/skills/pi-skills",
+        npx_flag: "--pi",
+        markers: &[".pi"],
+    },
+];
+
+/// Canonical names of the supported tools, in picker order.
+pub const SUPPORTED_TOOLS: [&str; 5] = [
+    TOOL_SPECS[0].name,
+    TOOL_SPECS[1].name,
+    TOOL_SPECS[2].name,
+    TOOL_SPECS[3].name,
+    TOOL_SPECS[4].name,
+];
+
+/// Looks up a tool by canonical name or alias.
+pub fn tool_spec(tool: &str) -> Option<&'static ToolSpec> {
+    TOOL_SPECS
+        .iter()
+        .find(|spec| spec.name == tool || spec.aliases.contains(&tool))
+}
+
+/// Maps an alias such as `claude_code` to its canonical tool name.
+pub fn canonical_tool(tool: &str) -> Option<&'static str> {
+    tool_spec(tool).map(|spec| spec.name)
+}
+
+/// Like [`canonical_tool`], but also consults user-defined aliases. Built-in
+/// names always win, so a user alias can never redirect a real tool name.
+pub fn resolve_tool(tool: &str, user_aliases: &BTreeMap<String, String>) -> Option<&'static str> {
+    canonical_tool(tool).or_else(|| {
+        user_aliases
+            .get(tool)
+            .and_then(|target| canonical_tool(target))
+    })
+}
+
+pub fn is_supported_tool(tool: &str) -> bool {
+    SUPPORTED_TOOLS.contains(&tool)
+}
+
 fn skills_base_path(tool: &str, scope: Scope) -> PathBuf {
     let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("~"));
+    // Unknown tools fall back to the shared `.agents` layout.
+    let spec = tool_spec(tool).unwrap_or(&TOOL_SPECS[1]);
 
-    match tool {
-        "claude-code" => match scope {
-            Scope::Project => PathBuf::from(".claude/skills"),
-            Scope::Global => home.join(".claude/skills"),
-        },
-        "codex" => match scope {
-            Scope::Project => PathBuf::from(".agents/skills"),
-            Scope::Global => home.join(".agents/skills"),
-        },
-        "cursor" => match scope {
-            Scope::Project => PathBuf::from(".cursor/skills"),
-            Scope::Global => home.join(".cursor/skills"),
-        },
-        "opencode" => match scope {
-            Scope::Project => PathBuf::from(".opencode/skills"),
-            Scope::Global => home.join(".config/opencode/skills"),
-        },
-        "pi" => match scope {
-            Scope::Project => PathBuf::from(".pi/skills/pi-skills"),
-            Scope::Global => home.join(".pi/agent/skills/pi-skills"),
-        },
-        _ => match scope {
-            Scope::Project => PathBuf::from(".agents/skills"),
-            Scope::Global => home.join(".agents/skills"),
-        },
+    match scope {
+        Scope::Project => PathBuf::from(spec.project_skills_dir),
+        Scope::Global => home.join(spec.global_skills_dir),
     }
 }
 
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::installer::tool_paths::{get_skill_folder, get_skill_path};
+    use crate::installer::tool_paths::{
+        canonical_tool, get_skill_folder, get_skill_path, resolve_tool, SUPPORTED_TOOLS, TOOL_SPECS,
+    };
     use crate::models::Scope;
     use std::path::PathBuf;
 
@@ -91,4 +93,44 @@ mod tests {
         let path = get_skill_path("unknown-tool", Scope::Project, "my-skill");
         assert_eq!(path, PathBuf::from(".agents/skills/my-skill/SKILL.md"));
     }
+
+    #[test]
+    fn test_aliases_resolve_to_canonical_names() {
+        assert_eq!(canonical_tool("claude-code"), Some("claude-code"));
+        assert_eq!(canonical_tool("claude_code"), Some("claude-code"));
+        assert_eq!(canonical_tool("open_code"), Some("opencode"));
+        assert_eq!(canonical_tool("vim"), None);
+        assert_eq!(
+            get_skill_path("claude_code", Scope::Project, "tdd"),
+            PathBuf::from(".claude/skills/tdd/SKILL.md")
+        );
+    }
+
+    #[test]
+    fn test_tool_names_and_aliases_are_unique() {
+        let mut seen = std::collections::HashSet::new();
+        for spec in &TOOL_SPECS {
+            assert!(seen.insert(spec.name), "duplicate name {}", spec.name);
+            for alias in spec.aliases {
+                assert!(seen.insert(alias), "duplicate alias {}", alias);
+            }
+        }
+        assert_eq!(
+            SUPPORTED_TOOLS.to_vec(),
+            TOOL_SPECS.iter().map(|s| s.name).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_resolve_tool_uses_user_aliases_after_builtins() {
+        let aliases = std::collections::BTreeMap::from([
+            ("cc".to_string(), "claude_code".to_string()),
+            ("codex".to_string(), "cursor".to_string()),
+            ("x".to_string(), "vim".to_string()),
+        ]);
+
+        assert_eq!(resolve_tool("cc", &aliases), Some("claude-code"));
+        assert_eq!(resolve_tool("codex", &aliases), Some("codex"));
+        assert_eq!(resolve_tool("x", &aliases), None);
+    }
 }
@@ -1,14 +1,26 @@
 use clap::Parser;
+use rulesify::cli::output::{write_json, OutputFormat};
 use rulesify::cli::{run, Cli};
+use rulesify::utils::exit_code_for;
 
 #[tokio::main]
 async fn main() {
-    env_logger::init();
-
     let cli = Cli::parse();
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(cli.log_filter()))
+        .init();
+    let output = cli.output;
 
-    if let Err(e) = run(cli).await {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
-    }
+    let code = match run(cli).await {
+        Ok(code) => code,
+        Err(e) => {
+            if output == OutputFormat::Json {
+                let error = serde_json::json!({ "error": e.to_string() });
+                let _ = write_json(&mut std::io::stdout().lock(), &error);
+            } else {
+                eprintln!("Error: {}", e);
+            }
+            exit_code_for(&e)
+        }
+    };
+    std::process::exit(code);
 }
@@ -1,7 +1,8 @@
-use crate::utils::{reconcile_project_config, Result};
+use crate::installer::{is_supported_tool, resolve_tool, SUPPORTED_TOOLS};
+use crate::utils::{parse_toml_file, reconcile_project_config, Result, RulesifyError};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
@@ -14,6 +15,9 @@ pub enum Scope {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstalledSkill {
     pub added: String,
+    /// Date the skill was last refreshed by `skill update`, if ever.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
     pub source: String,
     pub commit_sha: String,
     #[serde(default)]
@@ -25,17 +29,55 @@ pub struct InstalledSkill {
     pub covered_tools: Vec<String>,
 }
 
+/// File name of the per-project config, looked up from the project root.
+pub const PROJECT_CONFIG_FILE: &str = ".rulesify.toml";
+
+impl InstalledSkill {
+    /// Records a refreshed install. The date only moves when the commit changes.
+    pub fn mark_updated(&mut self, commit_sha: &str) {
+        if self.commit_sha != commit_sha {
+            self.commit_sha = commit_sha.to_string();
+            self.updated = Some(today());
+        }
+    }
+}
+
+/// Install dates are recorded in local time at day resolution.
+pub(crate) fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Schema version written to new configs. Older files are migrated on load.
+pub const CONFIG_VERSION: u32 = 1;
+
+const KNOWN_KEYS: [&str; 3] = ["version", "tools", "installed_skills"];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectConfig {
     pub version: u32,
     pub tools: Vec<String>,
+    #[serde(default)]
     pub installed_skills: HashMap<String, InstalledSkill>,
 }
 
+/// Problems found by [`ProjectConfig::validate_file`]. Errors make the config
+/// unusable; warnings are reported but the config still loads.
+#[derive(Debug, Default, Serialize)]
+pub struct ConfigIssues {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ConfigIssues {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
 impl Default for ProjectConfig {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: CONFIG_VERSION,
             tools: Vec::new(),
             installed_skills: HashMap::new(),
         }
@@ -58,7 +100,8 @@ impl ProjectConfig {
         self.installed_skills.insert(
             id.to_string(),
             InstalledSkill {
-                added: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                added: today(),
+                updated: None,
                 source: source.to_string(),
                 commit_sha: commit_sha.to_string(),
                 scope,
@@ -69,7 +112,7 @@ impl ProjectConfig {
 
     pub fn update_skill_sha(&mut self, id: &str, commit_sha: &str) {
         if let Some(skill) = self.installed_skills.get_mut(id) {
-            skill.commit_sha = commit_sha.to_string();
+            skill.mark_updated(commit_sha);
         }
     }
 
@@ -84,27 +127,290 @@ impl ProjectConfig {
             .collect()
     }
 
+    /// Finds the nearest project config by walking up from `start`, so
+    /// commands work from any subdirectory of a project.
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Parses a config file, migrating older schema versions in memory and
+    /// logging warnings for unknown keys or unsupported tools.
+    pub fn parse(path: &Path, content: &str) -> Result<Self> {
+        let mut table: toml::Table = parse_toml_file(path, content)?;
+        migrate(&mut table, path)?;
+        for key in unknown_keys(&table) {
+            log::warn!(
+                "{}: unknown key '{}' is not used by rulesify (left in the file as-is)",
+                path.display(),
+                key
+            );
+        }
+        let mut config: ProjectConfig = toml::Value::Table(table)
+            .try_into()
+            .map_err(|e| RulesifyError::ConfigError(format!("{}: {}", path.display(), e)))?;
+        config.canonicalize_tools(&user_tool_aliases());
+        for problem in config.tool_problems() {
+            log::warn!("{}: {}", path.display(), problem);
+        }
+        Ok(config)
+    }
+
+    /// Full check used by `rulesify config validate`.
+    pub fn validate_file(path: &Path) -> Result<ConfigIssues> {
+        let content = std::fs::read_to_string(path)?;
+        let mut table: toml::Table = parse_toml_file(path, &content)?;
+        let mut issues = ConfigIssues::default();
+
+        issues
+            .warnings
+            .extend(unknown_keys(&table).into_iter().map(|key| {
+                format!(
+                    "unknown key '{}' (valid keys: {})",
+                    key,
+                    KNOWN_KEYS.join(", ")
+                )
+            }));
+        if table.get("version").is_none() {
+            issues
+                .warnings
+                .push(format!("missing 'version'; treated as {}", CONFIG_VERSION));
+        }
+
+        if let Err(e) = migrate(&mut table, path) {
+            issues.errors.push(e.to_string());
+            return Ok(issues);
+        }
+        match toml::Value::Table(table).try_into::<ProjectConfig>() {
+            Ok(mut config) => {
+                config.canonicalize_tools(&user_tool_aliases());
+                issues.errors.extend(config.tool_problems());
+            }
+            Err(e) => issues.errors.push(e.to_string()),
+        }
+        Ok(issues)
+    }
+
+    /// Rewrites built-in aliases such as `claude_code`, and the user's own
+    /// `[tool_aliases]`, to their canonical names.
+    pub fn canonicalize_tools(&mut self, user_aliases: &BTreeMap<String, String>) {
+        for tool in &mut self.tools {
+            if let Some(name) = resolve_tool(tool, user_aliases) {
+                *tool = name.to_string();
+            }
+        }
+    }
+
+    /// Configured tools that rulesify cannot install skills for.
+    pub fn tool_problems(&self) -> Vec<String> {
+        if self.tools.is_empty() {
+            return vec!["no tools configured".to_string()];
+        }
+        self.tools
+            .iter()
+            .filter(|t| !is_supported_tool(t))
+            .map(|t| {
+                format!(
+                    "unsupported tool '{}' (supported: {})",
+                    t,
+                    SUPPORTED_TOOLS.join(", ")
+                )
+            })
+            .collect()
+    }
+
     pub fn reconcile_and_load(path: &Path) -> Result<Option<Self>> {
         if !path.exists() {
+            log::debug!("No project config at {}", path.display());
             return Ok(None);
         }
 
+        log::debug!("Project config: {}", path.display());
+
         let content = std::fs::read_to_string(path)?;
-        let mut config: ProjectConfig = toml::from_str(&content)?;
+        let mut config = Self::parse(path, &content)?;
 
-        reconcile_project_config(&mut config);
+        // Only touch the file when reconciliation dropped stale skills, so
+        // reading commands never rewrite comments, unknown keys or the
+        // on-disk schema version.
+        let removed = reconcile_project_config(&mut config);
+        if removed.is_empty() {
+            return Ok(Some(config));
+        }
 
-        if config.installed_skills.is_empty() {
+        if config.installed_skills.is_empty() && config.tools.is_empty() {
             if let Err(e) = std::fs::remove_file(path) {
                 log::error!("Failed to remove empty config file: {}", e);
             }
             return Ok(None);
         }
 
-        if let Err(e) = std::fs::write(path, toml::to_string_pretty(&config)?) {
+        if let Err(e) = remove_installed_entries(path, &content, &removed) {
             log::error!("Failed to save reconciled project config: {}", e);
         }
 
         Ok(Some(config))
     }
 }
+
+impl ProjectConfig {
+    /// Saves the config to `path`. An existing file is edited in place: only
+    /// `tools`, an already-present `version` and the `installed_skills.<id>`
+    /// entries that changed are rewritten, so user comments and unknown keys
+    /// survive. A missing or unparsable file is written from scratch.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let existing = std::fs::read_to_string(path).ok();
+        let content = match existing.as_deref().map(|c| self.merge_into(c)) {
+            Some(Ok(merged)) => merged,
+            _ => toml::to_string_pretty(self)?,
+        };
+        crate::utils::fs::atomic_write(path, content)?;
+        Ok(())
+    }
+
+    /// Rewrites only the `tools` entry of the file at `path`, leaving every
+    /// other line untouched. Falls back to [`ProjectConfig::save`] when the
+    /// file is missing or unparsable.
+    pub fn save_tools(&self, path: &Path) -> Result<()> {
+        let doc = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|c| c.parse::<toml_edit::DocumentMut>().ok());
+        let Some(mut doc) = doc else {
+            return self.save(path);
+        };
+        doc["tools"] = toml_edit::value(self.tools.iter().collect::<toml_edit::Array>());
+        crate::utils::fs::atomic_write(path, doc.to_string())?;
+        Ok(())
+    }
+
+    /// Applies this config to an existing TOML document, touching only the
+    /// entries whose values differ.
+    pub(crate) fn merge_into(&self, content: &str) -> Result<String> {
+        let mut doc = content
+            .parse::<toml_edit::DocumentMut>()
+            .map_err(|e| RulesifyError::ConfigError(e.to_string()))?;
+        let old: toml::Table = toml::from_str(content)?;
+        let fresh_text = toml::to_string_pretty(self)?;
+        let new: toml::Table = toml::from_str(&fresh_text)?;
+        let mut fresh = fresh_text
+            .parse::<toml_edit::DocumentMut>()
+            .map_err(|e| RulesifyError::ConfigError(e.to_string()))?;
+
+        if old.get("tools") != new.get("tools") {
+            doc["tools"] = fresh["tools"].clone();
+        }
+        if doc.contains_key("version") && old.get("version") != new.get("version") {
+            doc["version"] = fresh["version"].clone();
+        }
+
+        let skills_of = |table: &toml::Table| {
+            table
+                .get("installed_skills")
+                .and_then(|v| v.as_table())
+                .cloned()
+                .unwrap_or_default()
+        };
+        let (old_skills, new_skills) = (skills_of(&old), skills_of(&new));
+        if old_skills == new_skills {
+            return Ok(doc.to_string());
+        }
+
+        let fresh_skills = fresh
+            .remove("installed_skills")
+            .unwrap_or_else(|| toml_edit::Item::Table(toml_edit::Table::new()));
+        let Some(skills) = doc
+            .get_mut("installed_skills")
+            .and_then(|item| item.as_table_mut())
+        else {
+            doc["installed_skills"] = fresh_skills;
+            return Ok(doc.to_string());
+        };
+        for id in old_skills.keys().filter(|id| !new_skills.contains_key(*id)) {
+            skills.remove(id);
+        }
+        for (id, value) in &new_skills {
+            if old_skills.get(id) != Some(value) {
+                skills.insert(id, fresh_skills[id.as_str()].clone());
+            }
+        }
+        Ok(doc.to_string())
+    }
+}
+
+/// The `[tool_aliases]` table from the global config, read without the
+/// reconciliation `GlobalConfig::load` performs. A missing or broken global
+/// config just means no user aliases.
+fn user_tool_aliases() -> BTreeMap<String, String> {
+    let path = crate::models::get_global_config_path();
+    if !path.exists() {
+        return BTreeMap::new();
+    }
+    match crate::models::GlobalConfig::load_from(&path) {
+        Ok(config) => config.tool_aliases,
+        Err(e) => {
+            log::debug!("Ignoring tool aliases from {}: {}", path.display(), e);
+            BTreeMap::new()
+        }
+    }
+}
+
+/// Deletes `installed_skills.<id>` entries from the original document,
+/// keeping everything else in the file exactly as the user wrote it.
+fn remove_installed_entries(path: &Path, content: &str, ids: &[String]) -> Result<()> {
+    let mut doc: toml_edit::DocumentMut = content
+        .parse()
+        .map_err(|e| RulesifyError::ConfigError(format!("{}: {}", path.display(), e)))?;
+    if let Some(skills) = doc
+        .get_mut("installed_skills")
+        .and_then(|item| item.as_table_like_mut())
+    {
+        for id in ids {
+            skills.remove(id);
+        }
+    }
+    crate::utils::fs::atomic_write(path, doc.to_string())?;
+    Ok(())
+}
+
+fn unknown_keys(table: &toml::Table) -> Vec<String> {
+    table
+        .keys()
+        .filter(|k| !KNOWN_KEYS.contains(&k.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Upgrades a raw config table to [`CONFIG_VERSION`]. Each future schema
+/// change adds a step here that rewrites the table from one version to the
+/// next, so old files keep loading.
+fn migrate(table: &mut toml::Table, path: &Path) -> Result<()> {
+    let version = match table.get("version") {
+        // Files written before the field existed use the first schema.
+        None => 1,
+        Some(value) => value
+            .as_integer()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| {
+                RulesifyError::ConfigError(format!(
+                    "{}: 'version' must be a positive integer",
+                    path.display()
+                ))
+            })?,
+    };
+
+    if version > CONFIG_VERSION {
+        return Err(RulesifyError::ConfigError(format!(
+            "{} uses config version {}, but this rulesify supports up to {}. Upgrade rulesify.",
+            path.display(),
+            version,
+            CONFIG_VERSION
+        ))
+        .into());
+    }
+
+    table.insert("version".to_string(), toml::Value::from(CONFIG_VERSION));
+    Ok(())
+}
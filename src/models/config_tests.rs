@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::models::{InstalledSkill, ProjectConfig, Scope};
+    use crate::models::{GlobalConfig, InstalledSkill, ProjectConfig, Scope, CONFIG_VERSION};
 
     #[test]
     fn test_scope_default() {
@@ -35,6 +35,7 @@ mod tests {
             added: "2026-04-16".to_string(),
             source: "https://example.com".to_string(),
             commit_sha: "abc123".to_string(),
+            updated: None,
             scope: Scope::Global,
             covered_tools: vec![],
         };
@@ -123,6 +124,7 @@ commit_sha = "legacy123"
             added: "2026-05-22".to_string(),
             source: "https://example.com/foo".to_string(),
             commit_sha: "abc123".to_string(),
+            updated: None,
             scope: Scope::Global,
             covered_tools: vec!["pi".to_string()],
         };
@@ -165,4 +167,262 @@ scope = "project"
             .covered_tools
             .is_empty());
     }
+
+    #[test]
+    fn test_parse_error_reports_path_and_location() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(".rulesify.toml");
+        std::fs::write(&path, "version = 1\ntools = [\"cursor\"\n").unwrap();
+
+        let err = ProjectConfig::reconcile_and_load(&path).unwrap_err();
+        let message = err.to_string();
+
+        assert!(message.contains(&path.display().to_string()));
+        assert!(message.contains("line 2"));
+        assert!(message.contains('^'));
+        assert!(path.exists(), "broken config must not be removed");
+    }
+
+    #[test]
+    fn test_global_config_parse_error_reports_path() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(".registry.toml");
+        std::fs::write(&path, "version = \"one\"\n").unwrap();
+
+        let err = GlobalConfig::load_from(&path).unwrap_err();
+        let message = err.to_string();
+
+        assert!(message.contains(&path.display().to_string()));
+        assert!(message.contains("line 1"));
+    }
+
+    #[test]
+    fn test_discover_finds_config_in_parent_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let config = dir.path().join(".rulesify.toml");
+        std::fs::write(&config, "").unwrap();
+        let nested = dir.path().join("src/deep/module");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(ProjectConfig::discover(&nested), Some(config));
+    }
+
+    #[test]
+    fn test_discover_prefers_nearest_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".rulesify.toml"), "").unwrap();
+        let inner = dir.path().join("packages/app");
+        std::fs::create_dir_all(&inner).unwrap();
+        let inner_config = inner.join(".rulesify.toml");
+        std::fs::write(&inner_config, "").unwrap();
+
+        assert_eq!(
+            ProjectConfig::discover(&inner.join("src")),
+            Some(inner_config.clone())
+        );
+        assert_eq!(ProjectConfig::discover(&inner), Some(inner_config));
+    }
+
+    #[test]
+    fn test_discover_ignores_directory_named_like_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join(".rulesify.toml")).unwrap();
+
+        assert_ne!(
+            ProjectConfig::discover(dir.path()),
+            Some(dir.path().join(".rulesify.toml"))
+        );
+    }
+
+    fn write_config(content: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(".rulesify.toml");
+        std::fs::write(&path, content).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_parse_migrates_missing_version() {
+        let (_dir, path) = write_config("tools = [\"cursor\"]\n");
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let config = ProjectConfig::parse(&path, &content).unwrap();
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(config.installed_skills.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_newer_version() {
+        let (_dir, path) = write_config("version = 99\ntools = [\"cursor\"]\n");
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let err = ProjectConfig::parse(&path, &content)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("99"));
+    }
+
+    #[test]
+    fn test_parse_rejects_version_zero() {
+        let (_dir, path) = write_config("version = 0\ntools = [\"cursor\"]\n");
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let err = ProjectConfig::parse(&path, &content)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("positive integer"));
+    }
+
+    #[test]
+    fn test_validate_file_reports_unsupported_tools_and_unknown_keys() {
+        let (_dir, path) =
+            write_config("version = 1\ntools = [\"cursor\", \"vim\"]\ndefault_tools = []\n");
+
+        let issues = ProjectConfig::validate_file(&path).unwrap();
+
+        assert!(!issues.is_valid());
+        assert_eq!(issues.errors.len(), 1);
+        assert!(issues.errors[0].contains("vim"));
+        assert_eq!(issues.warnings.len(), 1);
+        assert!(issues.warnings[0].contains("default_tools"));
+    }
+
+    #[test]
+    fn test_validate_file_accepts_valid_config() {
+        let (_dir, path) = write_config("version = 1\ntools = [\"cursor\", \"pi\"]\n");
+
+        let issues = ProjectConfig::validate_file(&path).unwrap();
+
+        assert!(issues.is_valid());
+        assert!(issues.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_update_skill_sha_records_updated_date() {
+        let mut config = ProjectConfig::new();
+        config.add_skill("tdd", "https://example.com", "abc", Scope::Project, vec![]);
+        assert!(config.installed_skills["tdd"].updated.is_none());
+
+        config.update_skill_sha("tdd", "abc");
+        assert!(config.installed_skills["tdd"].updated.is_none());
+
+        config.update_skill_sha("tdd", "def");
+        let skill = &config.installed_skills["tdd"];
+        assert_eq!(skill.commit_sha, "def");
+        assert_eq!(skill.updated.as_deref(), Some(skill.added.as_str()));
+    }
+
+    #[test]
+    fn test_updated_round_trips_and_is_optional() {
+        let mut config = ProjectConfig::new();
+        config.add_skill("tdd", "https://example.com", "abc", Scope::Project, vec![]);
+        let toml_without = toml::to_string_pretty(&config).unwrap();
+        assert!(!toml_without.contains("updated"));
+
+        config.update_skill_sha("tdd", "def");
+        let toml_with = toml::to_string_pretty(&config).unwrap();
+        let parsed: ProjectConfig = toml::from_str(&toml_with).unwrap();
+        assert!(parsed.installed_skills["tdd"].updated.is_some());
+    }
+
+    #[test]
+    fn test_alias_problems_flags_unknown_targets_and_shadowing() {
+        let mut config = GlobalConfig::new();
+        config.tool_aliases.extend([
+            ("cc".to_string(), "claude-code".to_string()),
+            ("claude".to_string(), "claude-code".to_string()),
+            ("v".to_string(), "vim".to_string()),
+            ("cursor".to_string(), "codex".to_string()),
+        ]);
+
+        let problems = config.alias_problems();
+
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].contains("shadows"));
+        assert!(problems[1].contains("vim"));
+    }
+
+    #[test]
+    fn test_canonicalize_tools_resolves_user_aliases() {
+        let mut config = ProjectConfig::new();
+        config.tools = vec![
+            "cc".to_string(),
+            "claude_code".to_string(),
+            "vim".to_string(),
+        ];
+        let aliases = [("cc".to_string(), "codex".to_string())]
+            .into_iter()
+            .collect();
+
+        config.canonicalize_tools(&aliases);
+
+        assert_eq!(config.tools, vec!["codex", "claude-code", "vim"]);
+        assert_eq!(config.tool_problems().len(), 1);
+    }
+
+    #[test]
+    fn test_reconcile_and_load_leaves_unchanged_file_untouched() {
+        let content =
+            "# team tools\ntools = [\"cursor\"]\nmirror = \"https://mirror.example.com\"\n";
+        let (_dir, path) = write_config(content);
+
+        let config = ProjectConfig::reconcile_and_load(&path).unwrap().unwrap();
+
+        assert_eq!(config.tools, vec!["cursor".to_string()]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_reconcile_and_load_removes_stale_skill_but_keeps_user_content() {
+        let (_dir, path) = write_config(
+            "# team tools\nversion = 1\ntools = [\"cursor\"]\nmirror = \"x\"\n\n\
+             [installed_skills.rulesify-missing-skill]\nadded = \"2026-01-01\"\n\
+             source = \"https://example.com\"\ncommit_sha = \"abc\"\n",
+        );
+
+        let config = ProjectConfig::reconcile_and_load(&path).unwrap().unwrap();
+
+        assert!(config.installed_skills.is_empty());
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains("# team tools"));
+        assert!(written.contains("mirror = \"x\""));
+        assert!(!written.contains("rulesify-missing-skill"));
+    }
+
+    #[test]
+    fn test_save_keeps_comments_and_unknown_keys_when_adding_skill() {
+        let (_dir, path) = write_config(
+            "# keep me\nversion = 1\ntools = [\"cursor\"]\nfoo = \"bar\"\n\n[installed_skills]\n",
+        );
+        let content = std::fs::read_to_string(&path).unwrap();
+        let mut config = ProjectConfig::parse(&path, &content).unwrap();
+
+        config.add_skill("tdd", "https://example.com", "abc", Scope::Project, vec![]);
+        config.save(&path).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            written.starts_with("# keep me\nversion = 1\ntools = [\"cursor\"]\nfoo = \"bar\"\n")
+        );
+        let reparsed = ProjectConfig::parse(&path, &written).unwrap();
+        assert_eq!(reparsed.installed_skills["tdd"].commit_sha, "abc");
+
+        config.remove_skill("tdd");
+        config.save(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_save_without_changes_is_byte_identical() {
+        let content = "# keep me\ntools = [ \"cursor\" ]  # inline\nfoo = \"bar\"\n";
+        let (_dir, path) = write_config(content);
+        let config = ProjectConfig::parse(&path, content).unwrap();
+
+        config.save(&path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+    }
 }
@@ -1,7 +1,9 @@
+use crate::installer::{canonical_tool, SUPPORTED_TOOLS};
 use crate::models::{InstalledSkill, Scope};
+use crate::utils::{parse_toml_file, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
 pub fn get_global_config_path() -> PathBuf {
     dirs::config_dir()
@@ -14,6 +16,12 @@ pub fn get_global_config_path() -> PathBuf {
 pub struct GlobalConfig {
     pub version: u32,
     pub installed_skills: HashMap<String, HashMap<String, InstalledSkill>>,
+    /// Named skill bundles for `rulesify group` and `skill add --group`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, Vec<String>>,
+    /// User-defined tool name aliases, e.g. `cc = "claude-code"`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tool_aliases: BTreeMap<String, String>,
 }
 
 impl GlobalConfig {
@@ -21,25 +29,56 @@ impl GlobalConfig {
         Self {
             version: 1,
             installed_skills: HashMap::new(),
+            groups: BTreeMap::new(),
+            tool_aliases: BTreeMap::new(),
         }
     }
 
-    pub fn load() -> Self {
+    /// Aliases that point at unknown tools or collide with a built-in name
+    /// of a different tool.
+    pub fn alias_problems(&self) -> Vec<String> {
+        self.tool_aliases
+            .iter()
+            .filter_map(|(alias, target)| match canonical_tool(target) {
+                None => Some(format!(
+                    "alias '{}' points at unknown tool '{}' (supported: {})",
+                    alias,
+                    target,
+                    SUPPORTED_TOOLS.join(", ")
+                )),
+                Some(tool) => match canonical_tool(alias) {
+                    Some(builtin) if builtin != tool => Some(format!(
+                        "alias '{}' -> '{}' shadows built-in tool name for '{}'",
+                        alias, target, builtin
+                    )),
+                    _ => None,
+                },
+            })
+            .collect()
+    }
+
+    /// Loads the global config. A missing file yields an empty config; a file
+    /// that fails to parse is an error rather than being silently replaced.
+    pub fn load() -> Result<Self> {
         let path = get_global_config_path();
-        if path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&path) {
-                if let Ok(mut config) = toml::from_str(&content) {
-                    crate::utils::reconcile_global_config(&mut config);
-                    if !config.installed_skills.is_empty() {
-                        if let Err(e) = config.save() {
-                            log::error!("Failed to save reconciled global config: {}", e);
-                        }
-                    }
-                    return config;
-                }
+        log::debug!("Global config: {}", path.display());
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        let mut config = Self::load_from(&path)?;
+        crate::utils::reconcile_global_config(&mut config);
+        if !config.installed_skills.is_empty() {
+            if let Err(e) = config.save() {
+                log::error!("Failed to save reconciled global config: {}", e);
             }
         }
-        Self::new()
+        Ok(config)
+    }
+
+    /// Parses a global config file without reconciling it against disk.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        parse_toml_file(path, &content)
     }
 
     pub fn save(&self) -> std::io::Result<()> {
@@ -48,7 +87,8 @@ impl GlobalConfig {
             std::fs::create_dir_all(parent)?;
         }
         let content = toml::to_string_pretty(self).map_err(std::io::Error::other)?;
-        std::fs::write(&path, content)
+        log::debug!("Writing global config to {}", path.display());
+        crate::utils::fs::atomic_write(&path, content)
     }
 
     pub fn add_skill(
@@ -63,7 +103,8 @@ impl GlobalConfig {
         tool_skills.insert(
             id.to_string(),
             InstalledSkill {
-                added: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                added: crate::models::config::today(),
+                updated: None,
                 source: source.to_string(),
                 commit_sha: commit_sha.to_string(),
                 scope: Scope::Global,
@@ -121,7 +162,7 @@ impl GlobalConfig {
     pub fn update_skill_sha(&mut self, tool: &str, id: &str, commit_sha: &str) {
         if let Some(tool_skills) = self.installed_skills.get_mut(tool) {
             if let Some(skill) = tool_skills.get_mut(id) {
-                skill.commit_sha = commit_sha.to_string();
+                skill.mark_updated(commit_sha);
             }
         }
     }
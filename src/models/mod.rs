@@ -21,7 +21,9 @@ mod skill_metadata_tests;
 #[cfg(test)]
 mod skill_tests;
 
-pub use config::{InstalledSkill, ProjectConfig, Scope};
+pub use config::{
+    ConfigIssues, InstalledSkill, ProjectConfig, Scope, CONFIG_VERSION, PROJECT_CONFIG_FILE,
+};
 pub use context::ProjectContext;
 pub use domain::Domain;
 pub use global_config::{get_global_config_path, GlobalConfig};
@@ -30,6 +30,22 @@ impl Registry {
             .collect()
     }
 
+    /// `superseded_by` entries that point at skills missing from the registry.
+    pub fn dangling_replacements(&self) -> Vec<(String, String)> {
+        let mut dangling: Vec<(String, String)> = self
+            .skills
+            .iter()
+            .filter_map(|(id, s)| {
+                s.superseded_by
+                    .as_ref()
+                    .filter(|r| !self.skills.contains_key(*r))
+                    .map(|r| (id.clone(), r.clone()))
+            })
+            .collect();
+        dangling.sort();
+        dangling
+    }
+
     pub fn all_skills(&self) -> Vec<(String, Skill)> {
         self.skills
             .iter()
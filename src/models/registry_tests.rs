@@ -24,6 +24,8 @@ mod tests {
                 score: Some(85.0),
                 is_mega_skill: false,
                 dependencies: Vec::new(),
+                deprecated: false,
+                superseded_by: None,
             },
         );
 
@@ -36,4 +38,53 @@ mod tests {
         assert_eq!(registry.skills.len(), 1);
         assert!(registry.get_skill("tdd").is_some());
     }
+
+    #[test]
+    fn test_deprecated_fields_parse_and_flag_dangling_replacements() {
+        let content = r#"
+version = 1
+updated = "2026-04-14"
+
+[skills.old-tdd]
+name = "old-tdd"
+description = "Legacy TDD skill"
+source_url = "https://github.com/example/skills/tree/main/old-tdd"
+stars = 10
+last_updated = "2026-01-01"
+deprecated = true
+superseded_by = "tdd"
+
+[skills.old-lint]
+name = "old-lint"
+description = "Legacy lint skill"
+source_url = "https://github.com/example/skills/tree/main/old-lint"
+stars = 10
+last_updated = "2026-01-01"
+deprecated = true
+superseded_by = "missing-lint"
+
+[skills.tdd]
+name = "tdd"
+description = "TDD"
+source_url = "https://github.com/example/skills/tree/main/tdd"
+stars = 10
+last_updated = "2026-01-01"
+"#;
+        let registry: Registry = toml::from_str(content).unwrap();
+
+        let old = registry.get_skill("old-tdd").unwrap();
+        assert_eq!(
+            old.deprecation_notice().as_deref(),
+            Some("deprecated, use `tdd`")
+        );
+        assert!(registry
+            .get_skill("tdd")
+            .unwrap()
+            .deprecation_notice()
+            .is_none());
+        assert_eq!(
+            registry.dangling_replacements(),
+            vec![("old-lint".to_string(), "missing-lint".to_string())]
+        );
+    }
 }
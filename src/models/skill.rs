@@ -28,6 +28,14 @@ pub struct Skill {
 
     #[serde(default)]
     pub dependencies: Vec<String>,
+
+    /// Kept in the registry for existing installs but no longer recommended.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+
+    /// Registry id of the skill that replaces this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub superseded_by: Option<String>,
 }
 
 impl Skill {
@@ -38,4 +46,19 @@ impl Skill {
     pub fn matches_domain(&self, domain: &str) -> bool {
         self.domain == domain
     }
+
+    pub fn deprecation_notice(&self) -> Option<String> {
+        deprecation_notice(self.deprecated, self.superseded_by.as_deref())
+    }
+}
+
+/// Short notice for deprecated skills, e.g. "deprecated, use `new-id`".
+pub fn deprecation_notice(deprecated: bool, superseded_by: Option<&str>) -> Option<String> {
+    if !deprecated {
+        return None;
+    }
+    Some(match superseded_by {
+        Some(replacement) => format!("deprecated, use `{}`", replacement),
+        None => "deprecated".to_string(),
+    })
 }
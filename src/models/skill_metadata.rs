@@ -41,6 +41,8 @@ impl SkillMetadata {
             score: Some(score),
             is_mega_skill: self.is_mega_skill,
             dependencies: self.dependencies.clone(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 }
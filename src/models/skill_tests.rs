@@ -20,6 +20,8 @@ mod tests {
             score: Some(85.0),
             is_mega_skill: false,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         };
         assert_eq!(skill.stars, 1500);
         assert!(skill.install_action.unwrap().is_simple());
@@ -42,6 +44,8 @@ mod tests {
             score: Some(100.0),
             is_mega_skill: true,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         };
         assert!(skill.is_mega_skill);
         assert!(skill.install_action.unwrap().is_mega_skill_copy());
@@ -67,6 +71,8 @@ mod tests {
             score: Some(85.0),
             is_mega_skill: true,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         };
 
         let serialized = serde_json::to_string(&skill).unwrap();
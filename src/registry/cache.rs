@@ -1,5 +1,6 @@
 use crate::models::Registry;
-use crate::utils::Result;
+use crate::utils::fs::atomic_write;
+use crate::utils::{parse_toml_file, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -20,7 +21,7 @@ impl RegistryCache {
         }
 
         let content = fs::read_to_string(&self.cache_path)?;
-        let registry: Registry = toml::from_str(&content)?;
+        let registry: Registry = parse_toml_file(&self.cache_path, &content)?;
         Ok(Some(registry))
     }
 
@@ -29,7 +30,7 @@ impl RegistryCache {
         fs::create_dir_all(parent)?;
 
         let content = toml::to_string_pretty(registry)?;
-        fs::write(&self.cache_path, content)?;
+        atomic_write(&self.cache_path, content)?;
         Ok(())
     }
 
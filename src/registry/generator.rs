@@ -25,7 +25,7 @@ impl RegistryGenerator {
     }
 
     pub fn write(&self, registry: &Registry, path: &std::path::Path) -> Result<()> {
-        std::fs::write(path, self.to_toml(registry))?;
+        crate::utils::fs::atomic_write(path, self.to_toml(registry))?;
         Ok(())
     }
 }
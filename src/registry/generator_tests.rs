@@ -21,6 +21,8 @@ mod tests {
             score: Some(80.0),
             is_mega_skill: false,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
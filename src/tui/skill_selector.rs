@@ -760,31 +760,25 @@ impl SkillSelectorState {
         }
 
         match key {
-            KeyCode::Up => {
-                if self.current_skill_index > 0 {
-                    self.current_skill_index -= 1;
-                    self.update_skill_scroll_offset();
-                }
+            KeyCode::Up if self.current_skill_index > 0 => {
+                self.current_skill_index -= 1;
+                self.update_skill_scroll_offset();
             }
-            KeyCode::Down => {
-                if self.current_skill_index < self.filtered_skills.len().saturating_sub(1) {
-                    self.current_skill_index += 1;
-                    self.update_skill_scroll_offset();
-                }
+            KeyCode::Down
+                if self.current_skill_index < self.filtered_skills.len().saturating_sub(1) =>
+            {
+                self.current_skill_index += 1;
+                self.update_skill_scroll_offset();
             }
-            KeyCode::Left => {
-                if self.domain_index > 0 {
-                    self.domain_index -= 1;
-                    self.update_domain_scroll_offset();
-                    self.apply_filters();
-                }
+            KeyCode::Left if self.domain_index > 0 => {
+                self.domain_index -= 1;
+                self.update_domain_scroll_offset();
+                self.apply_filters();
             }
-            KeyCode::Right => {
-                if self.domain_index < self.domains.len().saturating_sub(1) {
-                    self.domain_index += 1;
-                    self.update_domain_scroll_offset();
-                    self.apply_filters();
-                }
+            KeyCode::Right if self.domain_index < self.domains.len().saturating_sub(1) => {
+                self.domain_index += 1;
+                self.update_domain_scroll_offset();
+                self.apply_filters();
             }
             KeyCode::Char('/') => {
                 self.skill_search_active = true;
@@ -846,17 +840,13 @@ impl SkillSelectorState {
                     self.update_scroll_offset(filtered_tags.len(), cols);
                 }
             }
-            KeyCode::Left => {
-                if self.tag_popup_index > 0 {
-                    self.tag_popup_index -= 1;
-                    self.update_scroll_offset(filtered_tags.len(), cols);
-                }
+            KeyCode::Left if self.tag_popup_index > 0 => {
+                self.tag_popup_index -= 1;
+                self.update_scroll_offset(filtered_tags.len(), cols);
             }
-            KeyCode::Right => {
-                if self.tag_popup_index < filtered_tags.len().saturating_sub(1) {
-                    self.tag_popup_index += 1;
-                    self.update_scroll_offset(filtered_tags.len(), cols);
-                }
+            KeyCode::Right if self.tag_popup_index < filtered_tags.len().saturating_sub(1) => {
+                self.tag_popup_index += 1;
+                self.update_scroll_offset(filtered_tags.len(), cols);
             }
             KeyCode::Char(c) => {
                 if c == ' ' && !filtered_tags.is_empty() {
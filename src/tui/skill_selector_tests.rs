@@ -21,6 +21,8 @@ mod tests {
             score: Some(80.0),
             is_mega_skill: false,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -39,6 +41,8 @@ mod tests {
             score: Some(90.0),
             is_mega_skill: true,
             dependencies: Vec::new(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -370,6 +374,8 @@ mod tests {
                     score: Some(80.0),
                     is_mega_skill: false,
                     dependencies: Vec::new(),
+                    deprecated: false,
+                    superseded_by: None,
                 },
             ),
             (
@@ -388,6 +394,8 @@ mod tests {
                     score: Some(80.0),
                     is_mega_skill: false,
                     dependencies: Vec::new(),
+                    deprecated: false,
+                    superseded_by: None,
                 },
             ),
         ];
@@ -418,6 +426,8 @@ mod tests {
                     score: Some(80.0),
                     is_mega_skill: false,
                     dependencies: Vec::new(),
+                    deprecated: false,
+                    superseded_by: None,
                 },
             ),
             (
@@ -436,6 +446,8 @@ mod tests {
                     score: Some(80.0),
                     is_mega_skill: false,
                     dependencies: Vec::new(),
+                    deprecated: false,
+                    superseded_by: None,
                 },
             ),
         ];
@@ -1,3 +1,4 @@
+use crate::installer::tool_paths::SUPPORTED_TOOLS as TOOLS;
 use crossterm::{
     event::{self, Event, KeyCode},
     execute,
@@ -10,8 +11,6 @@ use ratatui::{
 };
 use std::io;
 
-const TOOLS: [&str; 5] = ["claude-code", "codex", "cursor", "opencode", "pi"];
-
 pub struct ToolPicker {
     selected: Vec<bool>,
     cursor: usize,
@@ -61,15 +60,11 @@ impl ToolPicker {
 
             if let Event::Key(key) = event::read()? {
                 match key.code {
-                    KeyCode::Up => {
-                        if picker.cursor > 0 {
-                            picker.cursor -= 1;
-                        }
+                    KeyCode::Up if picker.cursor > 0 => {
+                        picker.cursor -= 1;
                     }
-                    KeyCode::Down => {
-                        if picker.cursor < TOOLS.len() - 1 {
-                            picker.cursor += 1;
-                        }
+                    KeyCode::Down if picker.cursor < TOOLS.len() - 1 => {
+                        picker.cursor += 1;
                     }
                     KeyCode::Char(' ') => {
                         picker.selected[picker.cursor] = !picker.selected[picker.cursor];
@@ -1,3 +1,5 @@
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,6 +10,9 @@ pub enum RulesifyError {
     #[error("Skill not found: {0}")]
     SkillNotFound(String),
 
+    #[error("Unknown group '{name}'.{hint}")]
+    GroupNotFound { name: String, hint: String },
+
     #[error("No skills match the current filters")]
     NoMatchingSkills,
 
@@ -23,6 +28,13 @@ pub enum RulesifyError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
+    #[error("Failed to parse {}:\n{source}", path.display())]
+    ConfigParse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
     #[error("TOML parse error: {0}")]
     TomlError(#[from] toml::de::Error),
 
@@ -43,3 +55,63 @@ pub enum RulesifyError {
 }
 
 pub type Result<T> = anyhow::Result<T>;
+
+/// Process exit codes. Scripts can rely on these, so only ever add new ones.
+pub mod exit_code {
+    pub const SUCCESS: i32 = 0;
+    /// Any error without a more specific code.
+    pub const ERROR: i32 = 1;
+    /// `doctor` or `config validate` ran but found problems.
+    pub const CHECK_FAILED: i32 = 2;
+    /// A skill or group id does not exist.
+    pub const NOT_FOUND: i32 = 3;
+    /// The config is missing, unparsable or invalid.
+    pub const CONFIG: i32 = 4;
+    // 5 is reserved for drift reported by a future `--check`.
+    /// The registry or GitHub could not be reached.
+    pub const NETWORK: i32 = 6;
+}
+
+impl RulesifyError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::SkillNotFound(_) | Self::GroupNotFound { .. } | Self::NoMatchingSkills => {
+                exit_code::NOT_FOUND
+            }
+            Self::ConfigError(_)
+            | Self::ConfigNotFound
+            | Self::ConfigParse { .. }
+            | Self::TomlError(_) => exit_code::CONFIG,
+            Self::RegistryFetch(_)
+            | Self::HttpError(_)
+            | Self::NetworkError(_)
+            | Self::GitHubApi(_) => exit_code::NETWORK,
+            _ => exit_code::ERROR,
+        }
+    }
+}
+
+/// Picks the exit code for an error, using the first [`RulesifyError`] in
+/// its context chain.
+pub fn exit_code_for(err: &anyhow::Error) -> i32 {
+    err.chain()
+        .find_map(|cause| {
+            cause
+                .downcast_ref::<RulesifyError>()
+                .map(RulesifyError::exit_code)
+                .or_else(|| cause.is::<reqwest::Error>().then_some(exit_code::NETWORK))
+        })
+        .unwrap_or(exit_code::ERROR)
+}
+
+/// Parses TOML read from `path`, keeping the path alongside the parser's
+/// line/column excerpt so a broken file can be located and fixed.
+pub fn parse_toml_file<T: DeserializeOwned>(path: &Path, content: &str) -> Result<T> {
+    toml::from_str(content).map_err(|source| {
+        RulesifyError::ConfigParse {
+            path: path.to_path_buf(),
+            source,
+        }
+        .into()
+    })
+}
@@ -0,0 +1,27 @@
+use crate::utils::{exit_code, exit_code_for, parse_toml_file, RulesifyError};
+use anyhow::Context;
+use std::path::Path;
+
+#[test]
+fn test_exit_codes_by_error_kind() {
+    let not_found = anyhow::Error::from(RulesifyError::SkillNotFound("tdd".to_string()));
+    assert_eq!(exit_code_for(&not_found), exit_code::NOT_FOUND);
+
+    let missing_config = anyhow::Error::from(RulesifyError::ConfigNotFound);
+    assert_eq!(exit_code_for(&missing_config), exit_code::CONFIG);
+
+    let network = anyhow::Error::from(RulesifyError::NetworkError("timeout".to_string()));
+    assert_eq!(exit_code_for(&network), exit_code::NETWORK);
+    assert_eq!(exit_code::NETWORK, 6);
+
+    let other = anyhow::anyhow!("something else");
+    assert_eq!(exit_code_for(&other), exit_code::ERROR);
+}
+
+#[test]
+fn test_exit_code_looks_through_context() {
+    let err = parse_toml_file::<toml::Table>(Path::new("broken.toml"), "a = [")
+        .context("loading config")
+        .unwrap_err();
+    assert_eq!(exit_code_for(&err), exit_code::CONFIG);
+}
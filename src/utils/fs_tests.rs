@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::fs::atomic_write;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn entries(dir: &TempDir) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_atomic_write_creates_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(".rulesify.toml");
+
+        atomic_write(&path, "version = 1\n").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "version = 1\n");
+        assert_eq!(entries(&dir), vec![".rulesify.toml".to_string()]);
+    }
+
+    #[test]
+    fn test_atomic_write_replaces_existing_contents() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "a much longer original body\n").unwrap();
+
+        atomic_write(&path, "short\n").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "short\n");
+        assert_eq!(entries(&dir), vec!["config.toml".to_string()]);
+    }
+
+    #[test]
+    fn test_atomic_write_fails_cleanly_for_missing_directory() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("missing/config.toml");
+
+        assert!(atomic_write(&path, "x").is_err());
+        assert!(entries(&dir).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        atomic_write(&path, "new").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_follows_symlink() {
+        let dir = TempDir::new().unwrap();
+        let real = dir.path().join("real.toml");
+        let link = dir.path().join("link.toml");
+        fs::write(&real, "old").unwrap();
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        atomic_write(&link, "new").unwrap();
+
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&real).unwrap(), "new");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_failure_leaves_original_untouched() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "original\n").unwrap();
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o555)).unwrap();
+
+        // Root (or CAP_DAC_OVERRIDE) ignores directory permissions, so the
+        // failure cannot be provoked; skip rather than report a false pass.
+        let probe = dir.path().join("probe");
+        if fs::write(&probe, "").is_ok() {
+            fs::remove_file(&probe).unwrap();
+            fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o755)).unwrap();
+            eprintln!("skipping: running with permission to write read-only dirs");
+            return;
+        }
+
+        let result = atomic_write(&path, "replacement\n");
+        let contents = fs::read_to_string(&path).unwrap();
+        let names = entries(&dir);
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(contents, "original\n");
+        assert_eq!(names, vec!["config.toml".to_string()]);
+    }
+}
@@ -1,10 +1,23 @@
 pub mod dependency;
 pub mod error;
+pub mod fs;
 pub mod reconcile;
+pub mod style;
+pub mod text;
 
 pub use dependency::check_all_dependencies;
-pub use error::{Result, RulesifyError};
+pub use error::{exit_code, exit_code_for, parse_toml_file, Result, RulesifyError};
 pub use reconcile::{reconcile_global_config, reconcile_project_config, skill_exists_on_disk};
+pub use style::Palette;
+pub use text::{closest_match, truncate_chars};
 
+#[cfg(test)]
+mod error_tests;
+#[cfg(test)]
+mod fs_tests;
 #[cfg(test)]
 mod reconcile_tests;
+#[cfg(test)]
+mod style_tests;
+#[cfg(test)]
+mod text_tests;
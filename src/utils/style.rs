@@ -0,0 +1,63 @@
+use crossterm::style::Stylize;
+use std::ffi::OsStr;
+
+/// Decides whether `auto` color mode should emit ANSI styling: only when
+/// writing to a terminal and `NO_COLOR` is unset or empty (https://no-color.org).
+pub fn auto_color_enabled(no_color: Option<&OsStr>, is_terminal: bool) -> bool {
+    let no_color_set = no_color.is_some_and(|v| !v.is_empty());
+    is_terminal && !no_color_set
+}
+
+/// Consistent success/warning/error styling for terminal output.
+/// A disabled palette returns text unchanged, so callers never need to
+/// branch on the color setting themselves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Palette {
+    enabled: bool,
+}
+
+impl Palette {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    pub fn plain() -> Self {
+        Self::new(false)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn success(&self, text: &str) -> String {
+        if self.enabled {
+            text.green().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn warn(&self, text: &str) -> String {
+        if self.enabled {
+            text.yellow().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn error(&self, text: &str) -> String {
+        if self.enabled {
+            text.red().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn dim(&self, text: &str) -> String {
+        if self.enabled {
+            text.dark_grey().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::style::{auto_color_enabled, Palette};
+    use std::ffi::OsStr;
+
+    #[test]
+    fn test_disabled_palette_has_no_ansi() {
+        let palette = Palette::plain();
+        for styled in [
+            palette.success("✓ done"),
+            palette.warn("! careful"),
+            palette.error("✗ failed"),
+            palette.dim("hint"),
+        ] {
+            assert!(
+                !styled.contains('\u{1b}'),
+                "unexpected escape in {:?}",
+                styled
+            );
+        }
+        assert_eq!(palette.success("✓ done"), "✓ done");
+    }
+
+    #[test]
+    fn test_enabled_palette_emits_ansi() {
+        let palette = Palette::new(true);
+        let styled = palette.error("✗ failed");
+        assert!(styled.contains('\u{1b}'));
+        assert!(styled.contains("✗ failed"));
+    }
+
+    #[test]
+    fn test_auto_color_requires_terminal() {
+        assert!(auto_color_enabled(None, true));
+        assert!(!auto_color_enabled(None, false));
+    }
+
+    #[test]
+    fn test_auto_color_honors_no_color() {
+        assert!(!auto_color_enabled(Some(OsStr::new("1")), true));
+        assert!(auto_color_enabled(Some(OsStr::new("")), true));
+    }
+}
@@ -0,0 +1,51 @@
+const ELLIPSIS: &str = "...";
+
+/// Truncates `s` to at most `max_chars` characters, replacing the tail with
+/// `...` when it does not fit. Counts `char`s rather than bytes so multi-byte
+/// text (em dashes, emoji, CJK) never gets split mid-character.
+pub fn truncate_chars(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        return s.to_string();
+    }
+
+    let ellipsis_len = ELLIPSIS.chars().count();
+    if max_chars <= ellipsis_len {
+        return s.chars().take(max_chars).collect();
+    }
+
+    let mut truncated: String = s.chars().take(max_chars - ellipsis_len).collect();
+    truncated.push_str(ELLIPSIS);
+    truncated
+}
+
+/// Returns the option closest to `input` by edit distance, for "did you mean"
+/// hints. Only reasonably close matches (a third of the input length, at
+/// least 2 edits) are suggested.
+pub fn closest_match<'a>(
+    input: &str,
+    options: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (input.chars().count() / 3).max(2);
+    options
+        .into_iter()
+        .map(|option| (edit_distance(input, option), option))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)))
+        .map(|(_, option)| option)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
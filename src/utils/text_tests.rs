@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::text::{closest_match, truncate_chars};
+
+    #[test]
+    fn test_short_string_unchanged() {
+        assert_eq!(truncate_chars("short", 10), "short");
+    }
+
+    #[test]
+    fn test_exact_length_unchanged() {
+        assert_eq!(truncate_chars("abcde", 5), "abcde");
+    }
+
+    #[test]
+    fn test_ascii_truncated_with_ellipsis() {
+        assert_eq!(truncate_chars("abcdefghij", 8), "abcde...");
+    }
+
+    #[test]
+    fn test_emoji_at_boundary() {
+        let s = format!("{}🚀🚀🚀 and more", "a".repeat(56));
+        let truncated = truncate_chars(&s, 60);
+        assert_eq!(truncated.chars().count(), 60);
+        assert_eq!(truncated, format!("{}🚀...", "a".repeat(56)));
+    }
+
+    #[test]
+    fn test_cjk_at_boundary() {
+        let s = "测试驱动开发是一种软件开发方法论";
+        let truncated = truncate_chars(s, 8);
+        assert_eq!(truncated, "测试驱动开...");
+    }
+
+    #[test]
+    fn test_em_dash_at_byte_57() {
+        let s = format!("{}—{}", "x".repeat(56), "y".repeat(20));
+        let truncated = truncate_chars(&s, 60);
+        assert_eq!(truncated, format!("{}—...", "x".repeat(56)));
+    }
+
+    #[test]
+    fn test_tiny_limit_has_no_ellipsis() {
+        assert_eq!(truncate_chars("🚀🚀🚀🚀", 2), "🚀🚀");
+    }
+
+    #[test]
+    fn test_closest_match_suggests_typo_fix() {
+        let options = ["test-driven-development", "playwright", "frontend-design"];
+        assert_eq!(closest_match("playwrite", options), Some("playwright"));
+    }
+
+    #[test]
+    fn test_closest_match_ignores_distant_options() {
+        let options = ["playwright", "frontend-design"];
+        assert_eq!(closest_match("kubernetes", options), None);
+    }
+}